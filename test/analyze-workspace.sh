@@ -0,0 +1,469 @@
+#!/bin/bash
+# CosmWasm CodeQL workspace analysis
+# Builds one CodeQL database for a Cargo workspace, runs every query in the
+# pack, and attributes findings to member crates by source path.
+#
+# Usage:
+#   ./test/analyze-workspace.sh <workspace-root>                  # All crates
+#   ./test/analyze-workspace.sh <workspace-root> --crate my-token # One crate
+#   ./test/analyze-workspace.sh <workspace-root> --out ./out      # Output dir
+#   ./test/analyze-workspace.sh <workspace-root> --rebuild        # Rebuild DB
+#   ./test/analyze-workspace.sh <workspace-root> --fail-on error  # Exit 1 on errors
+#   ./test/analyze-workspace.sh <workspace-root> --jobs 8         # Use 8 threads
+#   ./test/analyze-workspace.sh <workspace-root> --cache-dir .cache # Incremental
+#   ./test/analyze-workspace.sh <workspace-root> --diff-base main # Changed files only
+#   ./test/analyze-workspace.sh <workspace-root> --changed-files changed.txt
+#   ./test/analyze-workspace.sh <workspace-root> --format github  # PR annotations
+#   ./test/analyze-workspace.sh <workspace-root> --profile standard # One rule profile
+#   ./test/analyze-workspace.sh --list-rules                      # List the queries
+#
+# Prints one tab-separated line per finding: crate, rule id, severity,
+# confidence, file:line, message. Per-crate totals go to stderr. SARIF is
+# written to <out>/results.sarif. With --fail-on, exits 1 if any finding is
+# at or above that severity (error > warning > note). A member's
+# .cosmwasm-codeql.toml (see apply-config.sh) is applied to its findings
+# before they are reported and written.
+#
+# --format github prints GitHub Actions workflow commands
+# (::error file=...,line=...,col=...::message) instead of the tab-separated
+# lines, as test/sarif-to-github.sh does, so the findings become inline
+# annotations on the pull request. Paths are relative to the workspace root.
+#
+# --jobs N runs extraction and query evaluation on N threads (CodeQL's
+# --threads; 0 means one per core). Findings and SARIF results are sorted
+# by file, line and rule id, so the output is the same for any N.
+#
+# --cache-dir DIR keeps the content hash of every source file, the
+# file-level call graph (test/library-tests/FileDependencies.ql) and the
+# findings of the last run. A re-run re-analyzes every file of each member
+# crate holding a file whose hash changed or a file that calls into one at
+# any depth, since crate-wide queries (a SubMsg and its reply handler, reply
+# id and storage key collisions, the pause and admin checks) look across the
+# files of a crate; the files of every other crate keep their cached
+# findings. Those files, their callees in other crates, the crate roots and
+# files without functions (messages, state, errors) are copied to DIR/src
+# and analyzed as one database. Each re-analyzed file is listed on stderr. A change to a Cargo.toml, or --rebuild, re-analyzes
+# everything. A new call from a changed file into a file outside that set
+# is seen once the callee itself is re-analyzed.
+#
+# --diff-base REF analyzes only the Rust files that differ from git ref REF
+# (`git diff --name-only --relative REF` in the workspace root: commits
+# since REF and uncommitted changes to tracked files), plus the files that
+# call into them at any depth, together with every other file of their
+# member crates, as with --cache-dir, and reports only findings in those
+# crates.
+# --changed-files FILE takes the list from FILE instead, one path per line
+# relative to the workspace root, for CI systems that already know it. The
+# workspace is extracted once to compute the file-level call graph
+# (test/library-tests/FileDependencies.ql); the queries then run on a
+# database of the scanned files, their callees in other crates, the crate
+# roots and files without functions, as with --cache-dir. Each scanned file is listed on
+# stderr. A listed file that no longer exists is reported as deleted and
+# skipped: it has no findings, and the file declaring its module, which
+# changes with it, is scanned. A changed Cargo.toml scans every file.
+#
+# --profile NAME runs only the queries of that rule profile (standard,
+# strict or audit; see test/rules.sh) instead of every query in the pack.
+# A cache written with another profile is not reused.
+#
+# --list-rules prints every query in the rule registry (test/rules.sh): id,
+# severity, confidence, CWE and name, one tab-separated line each, or only
+# those of --profile. It needs no workspace.
+#
+# Requirements: codeql CLI, jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+PROJECT_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+
+WORKSPACE_ROOT=""
+CRATE_FILTER=""
+OUT_DIR="./cosmwasm-codeql-out"
+REBUILD=""
+FAIL_ON=""
+JOBS=""
+CACHE_DIR=""
+DIFF_BASE=""
+CHANGED_FILES=""
+FORMAT="tsv"
+PROFILE=""
+LIST_RULES=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --crate) CRATE_FILTER="$2"; shift 2 ;;
+    --out) OUT_DIR="$2"; shift 2 ;;
+    --rebuild) REBUILD="true"; shift ;;
+    --fail-on) FAIL_ON="$2"; shift 2 ;;
+    --jobs) JOBS="$2"; shift 2 ;;
+    --cache-dir) CACHE_DIR="$2"; shift 2 ;;
+    --diff-base) DIFF_BASE="$2"; shift 2 ;;
+    --changed-files) CHANGED_FILES="$2"; shift 2 ;;
+    --format) FORMAT="$2"; shift 2 ;;
+    --profile) PROFILE="$2"; shift 2 ;;
+    --list-rules) LIST_RULES="true"; shift ;;
+    *) WORKSPACE_ROOT="$1"; shift ;;
+  esac
+done
+
+PROFILE_ARGS=()
+[ -n "$PROFILE" ] && PROFILE_ARGS=(--profile "$PROFILE")
+[ -n "$LIST_RULES" ] && exec bash "$SCRIPT_DIR/rules.sh" --list "${PROFILE_ARGS[@]}"
+
+if [ -z "$WORKSPACE_ROOT" ] || [ ! -f "$WORKSPACE_ROOT/Cargo.toml" ] || [[ ! "$JOBS" =~ ^[0-9]*$ ]] ||
+  [[ ! "$FAIL_ON" =~ ^(error|warning|note)?$ ]] ||
+  [[ ! "$FORMAT" =~ ^(tsv|github)$ ]] || { [ -n "$DIFF_BASE" ] && [ -n "$CHANGED_FILES" ]; } ||
+  { [ -n "$CACHE_DIR" ] && [ -n "$DIFF_BASE$CHANGED_FILES" ]; }; then
+  echo "usage: analyze-workspace.sh <workspace-root> [--crate NAME] [--out DIR] [--rebuild] [--fail-on error|warning|note] [--jobs N] [--cache-dir DIR | --diff-base REF | --changed-files FILE] [--format tsv|github] [--profile NAME] | --list-rules [--profile NAME]" >&2
+  exit 2
+fi
+if [ -n "$CHANGED_FILES" ] && [ ! -f "$CHANGED_FILES" ]; then
+  echo "Changed-files list not found: $CHANGED_FILES" >&2
+  exit 2
+fi
+if [ -n "$DIFF_BASE" ] && ! git -C "$WORKSPACE_ROOT" rev-parse --verify --quiet "$DIFF_BASE^{commit}" >/dev/null; then
+  echo "Not a git ref in $WORKSPACE_ROOT: $DIFF_BASE" >&2
+  exit 2
+fi
+THREAD_ARGS=()
+[ -n "$JOBS" ] && THREAD_ARGS=(--threads="$JOBS")
+# The whole pack, or the profile's queries plus the suppression query
+QUERIES=("$PROJECT_ROOT/src/queries")
+if [ -n "$PROFILE" ]; then
+  profile_queries=$(bash "$SCRIPT_DIR/rules.sh" --json --profile "$PROFILE") || exit 2
+  QUERIES=("$PROJECT_ROOT/src/queries/AlertSuppression.ql")
+  while IFS= read -r query; do
+    QUERIES+=("$PROJECT_ROOT/$query")
+  done < <(jq -r '.rules[].query' <<< "$profile_queries")
+fi
+WORKSPACE_ROOT="$(cd "$WORKSPACE_ROOT" && pwd)"
+mkdir -p "$OUT_DIR"
+OUT_DIR="$(cd "$OUT_DIR" && pwd)"
+DB_PATH="$OUT_DIR/db"
+SARIF_FILE="$OUT_DIR/results.sarif"
+if [ -n "$CACHE_DIR" ]; then
+  mkdir -p "$CACHE_DIR"
+  CACHE_DIR="$(cd "$CACHE_DIR" && pwd)"
+fi
+
+# --- Discover member crates from `[workspace] members = [...]` ---
+# Prints the quoted entries of the members array (single- or multi-line).
+read_member_patterns() {
+  awk '
+    /^\[workspace\]/ { in_ws = 1; next }
+    /^\[/ { in_ws = 0 }
+    in_ws && /^[[:space:]]*members[[:space:]]*=/ { in_members = 1 }
+    in_members { print; if ($0 ~ /\]/) in_members = 0 }
+  ' "$WORKSPACE_ROOT/Cargo.toml" | grep -o '"[^"]*"' | tr -d '"'
+}
+
+# JSON object mapping member path (relative to the root) to crate name
+members_json="{}"
+while IFS= read -r pattern; do
+  for member_dir in "$WORKSPACE_ROOT"/$pattern; do
+    [ -f "$member_dir/Cargo.toml" ] || continue
+    rel="${member_dir#"$WORKSPACE_ROOT"/}"
+    crate=$(sed -n 's/^name[[:space:]]*=[[:space:]]*"\([^"]*\)".*/\1/p' "$member_dir/Cargo.toml" | head -1)
+    members_json=$(jq -c --arg path "$rel" --arg crate "${crate:-$rel}" '. + {($path): $crate}' <<< "$members_json")
+  done
+done < <(read_member_patterns)
+
+if [ "$members_json" = "{}" ]; then
+  echo "No [workspace] members found in $WORKSPACE_ROOT/Cargo.toml" >&2
+  exit 1
+fi
+echo "Members: $(jq -r '[.[]] | join(", ")' <<< "$members_json")" >&2
+
+# --- Build one database for the whole workspace ---
+analyze_full() {
+  if [ -d "$DB_PATH" ] && [ "$REBUILD" != "true" ]; then
+    echo "Using cached database: $DB_PATH" >&2
+  else
+    codeql database create "$DB_PATH" \
+      --language=rust \
+      --source-root="$WORKSPACE_ROOT" \
+      --overwrite \
+      "${THREAD_ARGS[@]}" \
+      2>&1 | tail -1 >&2
+  fi
+
+  codeql database analyze "$DB_PATH" "${QUERIES[@]}" \
+    --additional-packs="$PROJECT_ROOT" \
+    --format=sarifv2.1.0 \
+    --output="$SARIF_FILE" \
+    --rerun \
+    "${THREAD_ARGS[@]}" >/dev/null 2>&1
+}
+
+# --- Partial analysis (--cache-dir, --diff-base) ---
+sha256() {
+  if command -v sha256sum >/dev/null 2>&1; then
+    sha256sum "$1" | cut -d' ' -f1
+  else
+    shasum -a 256 "$1" | cut -d' ' -f1
+  fi
+}
+
+# Prints {"<path>": "<sha256>"} for the Rust sources and manifests, with
+# paths relative to the workspace root
+hash_sources() {
+  find "$WORKSPACE_ROOT" \( -name target -o -name .git -o -path "$OUT_DIR" -o -path "$CACHE_DIR" \) -prune \
+    -o -type f \( -name '*.rs' -o -name Cargo.toml -o -name Cargo.lock \) -print |
+    sort | while IFS= read -r file; do
+      printf '%s\t%s\n' "${file#"$WORKSPACE_ROOT"/}" "$(sha256 "$file")"
+    done | jq -Rn '[inputs | split("\t") | {key: .[0], value: .[1]}] | from_entries'
+}
+
+# Prints {"<file>": [files it calls into]} for every file of database $1
+# that defines a function; $2 is a scratch directory for the results
+file_dependencies() {
+  local bqrs="$2/dependencies.bqrs"
+  codeql query run --database="$1" --additional-packs="$PROJECT_ROOT" \
+    --output="$bqrs" "${THREAD_ARGS[@]}" \
+    "$SCRIPT_DIR/library-tests/FileDependencies.ql" >/dev/null 2>&1
+  codeql bqrs decode --format=json "$bqrs" |
+    jq '.["#select"].tuples | group_by(.[0])
+      | map({key: .[0][0], value: (map(.[1] | select(. != "")) | unique)}) | from_entries'
+}
+
+# jq definitions for planning a partial analysis. closure($edges; $seed) is
+# $seed and every file reachable from it over $edges; whole_crates($members;
+# $sources; $files) adds to $files every source of the member crates they
+# belong to (files outside every member count as one crate); copy($now; $deps;
+# $affected) lists what to extract to analyze $affected: those files, their
+# callees, the files that are not Rust sources, the crate roots and the
+# sources missing from $deps.
+PLAN_DEFS='
+  def closure($edges; $seed):
+    {set: $seed, done: false}
+    | until(.done;
+        .set as $s
+        | ([$edges | to_entries[] | select(.key as $k | $s | index([$k])) | .value[]] + $s | unique) as $next
+        | if $next == $s then .done = true else .set = $next end)
+    | .set;
+  def crate_of($members): . as $f
+    | [$members | keys[] | select(. as $path | $f | startswith($path + "/"))] | max_by(length) // "";
+  def whole_crates($members; $sources; $files):
+    ($files | map(crate_of($members)) | unique) as $crates
+    | [$sources[] | select(crate_of($members) as $c | $crates | index([$c]))] + $files | unique;
+  def reverse($edges): [$edges | to_entries[] | .key as $k | .value[] | {key: ., value: $k}]
+    | group_by(.key) | map({key: .[0].key, value: map(.value)}) | from_entries;
+  def copy($now; $deps; $affected):
+    [$now | keys[] | select(endswith(".rs"))] as $sources
+    | closure($deps; $affected)
+      + [$now | keys[] | select(endswith(".rs") | not)]
+      + [$sources[] | select(test("(^|/)(lib|main|mod)\\.rs$"))]
+      + [$sources[] | select(. as $f | $deps | has($f) | not)]
+    | unique | map(select($now[.] != null));
+'
+
+# Copies the files of plan $1 (.copy) to directory $2, extracts them to
+# database $3 and runs the queries on it, writing $SARIF_FILE
+analyze_partial() {
+  local plan="$1" partial_root="$2" partial_db="$3"
+  rm -rf "$partial_root"
+  jq -r '.copy[]' <<< "$plan" | while IFS= read -r path; do
+    mkdir -p "$partial_root/$(dirname "$path")"
+    cp "$WORKSPACE_ROOT/$path" "$partial_root/$path"
+  done
+  codeql database create "$partial_db" \
+    --language=rust \
+    --source-root="$partial_root" \
+    --overwrite \
+    "${THREAD_ARGS[@]}" \
+    2>&1 | tail -1 >&2
+  codeql database analyze "$partial_db" "${QUERIES[@]}" \
+    --additional-packs="$PROJECT_ROOT" \
+    --format=sarifv2.1.0 \
+    --output="$SARIF_FILE" \
+    --rerun \
+    "${THREAD_ARGS[@]}" >/dev/null 2>&1
+}
+
+analyze_incremental() {
+  local cache_file="$CACHE_DIR/cache.json"
+  local hashes cached plan
+  hashes=$(hash_sources)
+  cached='{"hashes": {}, "deps": {}, "sarif": null}'
+  [ -f "$cache_file" ] && [ "$REBUILD" != "true" ] && cached=$(cat "$cache_file")
+
+  # affected: the crates of the changed files and their dependents; copy: what
+  # to extract for them
+  plan=$(jq -n --argjson now "$hashes" --argjson cache "$cached" --arg profile "$PROFILE" \
+    --argjson members "$members_json" "$PLAN_DEFS"'
+    [$now | keys[] | select(endswith(".rs"))] as $sources
+    | ([$now, $cache.hashes | keys[]] | unique | map(select($now[.] != $cache.hashes[.]))) as $changed
+    | (if $cache.sarif == null or ($cache.profile // "") != $profile or any($changed[]; endswith("Cargo.toml"))
+       then $sources
+       else closure(reverse($cache.deps); $changed) | map(select($now[.] != null))
+         | whole_crates($members; $sources; .) end) as $affected
+    | {affected: $affected, copy: copy($now; $cache.deps; $affected)}')
+
+  if [ "$(jq '.affected | length' <<< "$plan")" -eq 0 ]; then
+    echo "No source changes; reusing cached findings" >&2
+    jq '.sarif' <<< "$cached" > "$SARIF_FILE"
+    return
+  fi
+  jq -r '.affected[] | "Re-analyzing: \(.)"' <<< "$plan" >&2
+  analyze_partial "$plan" "$CACHE_DIR/src" "$CACHE_DIR/db"
+
+  # Fresh findings for affected files, cached ones for the rest
+  local deps
+  deps=$(file_dependencies "$CACHE_DIR/db" "$CACHE_DIR")
+  jq --argjson plan "$plan" --argjson cache "$cached" --argjson now "$hashes" '
+    def uri: .locations[0].physicalLocation.artifactLocation.uri;
+    .runs[0].results = (
+      [$cache.sarif.runs[]?.results[]?
+        | select(uri as $u | $now[$u] != null and ($plan.affected | index([$u]) | not))]
+      + [.runs[].results[] | select(uri as $u | $plan.affected | index([$u]))])
+  ' "$SARIF_FILE" > "$SARIF_FILE.tmp"
+  mv "$SARIF_FILE.tmp" "$SARIF_FILE"
+
+  jq -n --argjson now "$hashes" --argjson cache "$cached" --argjson plan "$plan" \
+    --argjson deps "$deps" --slurpfile sarif "$SARIF_FILE" --arg profile "$PROFILE" '
+    {
+      hashes: $now,
+      profile: $profile,
+      deps: ($cache.deps
+        | with_entries(select(.key as $k | $now[$k] != null and ($plan.affected | index([$k]) | not)))
+        + ($deps | with_entries(select(.key as $k | $plan.affected | index([$k]))))),
+      sarif: $sarif[0]
+    }' > "$cache_file"
+}
+
+# Prints the files changed since $DIFF_BASE, or listed in $CHANGED_FILES,
+# relative to the workspace root, as a JSON array
+changed_files() {
+  if [ -n "$CHANGED_FILES" ]; then
+    sed -e 's/^[[:space:]]*//' -e 's/[[:space:]]*$//' -e 's#^\./##' "$CHANGED_FILES"
+  else
+    git -C "$WORKSPACE_ROOT" diff --name-only --relative "$DIFF_BASE" --
+  fi | jq -Rn '[inputs | select(. != "")] | unique'
+}
+
+analyze_diff() {
+  local diff_dir="$OUT_DIR/diff" hashes changed deps plan
+  hashes=$(hash_sources)
+  changed=$(changed_files)
+  jq -r --argjson now "$hashes" '.[] | select($now[.] == null and endswith(".rs")) | "Deleted: \(.)"' \
+    <<< "$changed" >&2
+
+  # The file-level call graph of the whole workspace, for the dependents
+  mkdir -p "$diff_dir"
+  codeql database create "$DB_PATH" \
+    --language=rust \
+    --source-root="$WORKSPACE_ROOT" \
+    --overwrite \
+    "${THREAD_ARGS[@]}" \
+    2>&1 | tail -1 >&2
+  deps=$(file_dependencies "$DB_PATH" "$diff_dir")
+
+  # affected: the crates of the changed sources that still exist and of their
+  # dependents
+  plan=$(jq -n --argjson now "$hashes" --argjson deps "$deps" --argjson changed "$changed" \
+    --argjson members "$members_json" "$PLAN_DEFS"'
+    [$now | keys[] | select(endswith(".rs"))] as $sources
+    | [$changed[] | select($now[.] != null)] as $present
+    | (if any($present[]; endswith("Cargo.toml"))
+       then $sources
+       else closure(reverse($deps); [$present[] | select(endswith(".rs"))]) | map(select($now[.] != null))
+         | whole_crates($members; $sources; .) end)
+      as $affected
+    | {affected: $affected, copy: copy($now; $deps; $affected)}')
+
+  if [ "$(jq '.affected | length' <<< "$plan")" -eq 0 ]; then
+    echo "No changed Rust sources; nothing to analyze" >&2
+    jq -n '{version: "2.1.0", runs: [{tool: {driver: {name: "CodeQL", rules: []}}, results: []}]}' > "$SARIF_FILE"
+    return
+  fi
+  jq -r '.affected[] | "Scanning: \(.)"' <<< "$plan" >&2
+  analyze_partial "$plan" "$diff_dir/src" "$diff_dir/db"
+
+  # Only the findings in the scanned crates
+  jq --argjson plan "$plan" '
+    .runs[].results |= map(select(.locations[0].physicalLocation.artifactLocation.uri as $u
+      | $plan.affected | index([$u])))
+  ' "$SARIF_FILE" > "$SARIF_FILE.tmp"
+  mv "$SARIF_FILE.tmp" "$SARIF_FILE"
+}
+
+if [ -n "$CACHE_DIR" ]; then
+  analyze_incremental
+elif [ -n "$DIFF_BASE$CHANGED_FILES" ]; then
+  analyze_diff
+else
+  analyze_full
+fi
+
+# Result order depends on evaluation order; fix it so runs are comparable
+jq '.runs[].results |= sort_by(
+      .locations[0].physicalLocation.artifactLocation.uri,
+      .locations[0].physicalLocation.region.startLine,
+      .ruleId,
+      (.locations[0].physicalLocation.region.startColumn // 0),
+      .message.text)' "$SARIF_FILE" > "$SARIF_FILE.tmp"
+mv "$SARIF_FILE.tmp" "$SARIF_FILE"
+
+# Link each rule to its help page: CodeQL leaves helpUri unset for local packs
+jq --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" '
+  ($registry | map({key: .id, value: .helpUri}) | from_entries) as $help
+  | (.runs[].tool | .driver.rules[]?, .extensions[]?.rules[]?)
+    |= (if .helpUri == null and $help[.id] then .helpUri = $help[.id] else . end)
+' "$SARIF_FILE" > "$SARIF_FILE.tmp"
+mv "$SARIF_FILE.tmp" "$SARIF_FILE"
+
+# --- Apply each member's .cosmwasm-codeql.toml ---
+while IFS= read -r rel; do
+  [ -f "$WORKSPACE_ROOT/$rel/.cosmwasm-codeql.toml" ] || continue
+  echo "Applying $rel/.cosmwasm-codeql.toml" >&2
+  bash "$SCRIPT_DIR/apply-config.sh" "$WORKSPACE_ROOT/$rel" "$SARIF_FILE" --prefix "$rel" \
+    --out "$SARIF_FILE.tmp"
+  mv "$SARIF_FILE.tmp" "$SARIF_FILE"
+done < <(jq -r 'keys[]' <<< "$members_json")
+
+# --- Attribute each result to the member with the longest matching path ---
+CRATE_OF='
+  def crate_of($members):
+    .locations[0].physicalLocation.artifactLocation.uri as $uri
+    | [$members | to_entries[] | select(.key as $path | $uri | startswith($path + "/"))]
+    | max_by(.key | length) | .value // "(workspace)";
+'
+findings=$(jq -r --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" --argjson members "$members_json" --arg only "$CRATE_FILTER" "$CRATE_OF"'
+  ([$registry[], .runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?]
+   | map({key: .id, value: .}) | from_entries) as $rules
+  | .runs[].results[]
+  | select((.suppressions // []) | length == 0)
+  | .locations[0].physicalLocation as $loc
+  | crate_of($members) as $crate
+  | select($only == "" or $crate == $only)
+  | [$crate, .ruleId,
+     (.level // $rules[.ruleId].defaultConfiguration.level // "warning"),
+     ($rules[.ruleId].properties.precision // "unknown"),
+     "\($loc.artifactLocation.uri):\($loc.region.startLine)", .message.text]
+  | @tsv
+' "$SARIF_FILE")
+
+if [ "$FORMAT" = "github" ]; then
+  jq --argjson members "$members_json" --arg only "$CRATE_FILTER" "$CRATE_OF"'
+    .runs[].results |= map(select($only == "" or crate_of($members) == $only))
+  ' "$SARIF_FILE" > "$OUT_DIR/github.sarif"
+  bash "$SCRIPT_DIR/sarif-to-github.sh" "$OUT_DIR/github.sarif"
+  rm -f "$OUT_DIR/github.sarif"
+elif [ -n "$findings" ]; then
+  echo "$findings"
+fi
+echo "" >&2
+echo "Findings per crate:" >&2
+jq -r '.[]' <<< "$members_json" | while IFS= read -r crate; do
+  [ -n "$CRATE_FILTER" ] && [ "$crate" != "$CRATE_FILTER" ] && continue
+  count=$(printf '%s\n' "$findings" | awk -F'\t' -v c="$crate" '$1 == c' | grep -c . || true)
+  echo "  $crate: $count" >&2
+done
+
+if [ -n "$FAIL_ON" ]; then
+  failing=$(printf '%s\n' "$findings" | awk -F'\t' -v fail_on="$FAIL_ON" '
+    BEGIN { rank["error"] = 3; rank["warning"] = 2; rank["note"] = 1 }
+    NF && rank[$3] >= rank[fail_on]
+  ' | grep -c . || true)
+  echo "$failing finding(s) at or above $FAIL_ON" >&2
+  [ "$failing" -eq 0 ] || exit 1
+fi
@@ -0,0 +1,158 @@
+#!/bin/bash
+# Applies a crate's .cosmwasm-codeql.toml to SARIF output before reporting,
+# so teams can tune the pack per contract without CLI flags in CI.
+#
+# Usage:
+#   ./test/apply-config.sh <crate-root> <results.sarif>                # Filtered SARIF on stdout
+#   ./test/apply-config.sh <crate-root> <results.sarif> --out FILE     # Write to a file
+#   ./test/apply-config.sh <crate-root> <results.sarif> --config FILE  # Config other than <crate-root>/.cosmwasm-codeql.toml
+#   ./test/apply-config.sh <crate-root> <results.sarif> --prefix PATH  # Crate is at PATH in the SARIF uris (workspaces)
+#
+# Supported keys (query ids with or without the `cosmwasm/` prefix):
+#   disabled_queries = ["ibc-cei-violation"]                # Drop these results
+#   severity_overrides = { unchecked-cosmwasm-arithmetic = "error" }
+#   exclude_paths = ["src/testing/**"]                      # Globs relative to the crate root
+#
+# Overrides set each result's `level` (error, warning, note; `recommendation`
+# is `note`). With --prefix, only results under PATH/ are affected. Without
+# a config file the SARIF is passed through unchanged.
+#
+# Requirements: jq
+set -eo pipefail
+
+CRATE_ROOT=""
+SARIF_FILE=""
+CONFIG_FILE=""
+PREFIX=""
+OUT_FILE=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --config) CONFIG_FILE="$2"; shift 2 ;;
+    --prefix) PREFIX="${2%/}"; shift 2 ;;
+    --out) OUT_FILE="$2"; shift 2 ;;
+    *)
+      if [ -z "$CRATE_ROOT" ]; then CRATE_ROOT="$1"; else SARIF_FILE="$1"; fi
+      shift
+      ;;
+  esac
+done
+
+if [ -z "$CRATE_ROOT" ] || [ ! -f "$SARIF_FILE" ]; then
+  echo "usage: apply-config.sh <crate-root> <results.sarif> [--config FILE] [--prefix PATH] [--out FILE]" >&2
+  exit 2
+fi
+CONFIG_FILE="${CONFIG_FILE:-$CRATE_ROOT/.cosmwasm-codeql.toml}"
+
+# Prints the config as JSON: {disabled_queries: [...], severity_overrides:
+# {...}, exclude_paths: [...]}. Handles the TOML this file needs: string
+# arrays (one or several lines), an inline table or a [severity_overrides]
+# table of strings, and # comments.
+read_config() {
+  awk '
+    function strip(s) { gsub(/^[[:space:]]+|[[:space:]]+$/, "", s); return s }
+    function unquote(s) { s = strip(s); if (s ~ /^".*"$/) s = substr(s, 2, length(s) - 2); return s }
+    function emit_list(key, body,    n, parts, i, item) {
+      n = split(body, parts, ",")
+      for (i = 1; i <= n; i++) {
+        item = unquote(parts[i])
+        if (item != "") printf "%s\t%s\n", key, item
+      }
+    }
+    function emit_table(body,    n, parts, i, kv) {
+      n = split(body, parts, ",")
+      for (i = 1; i <= n; i++) {
+        if (strip(parts[i]) == "") continue
+        split(parts[i], kv, "=")
+        printf "severity_overrides\t%s\t%s\n", unquote(kv[1]), unquote(kv[2])
+      }
+    }
+    {
+      line = $0
+      sub(/[[:space:]]*#.*$/, "", line)
+      line = strip(line)
+      if (line == "") next
+    }
+    pending != "" {
+      buffer = buffer " " line
+      if (line ~ /\]$/) { sub(/\]$/, "", buffer); emit_list(pending, buffer); pending = "" }
+      next
+    }
+    line ~ /^\[severity_overrides\]$/ { section = "severity_overrides"; next }
+    line ~ /^\[/ { printf "error\tunknown table %s\n", line; section = "other"; next }
+    section == "severity_overrides" { emit_table(line); next }
+    {
+      key = strip(substr(line, 1, index(line, "=") - 1))
+      value = strip(substr(line, index(line, "=") + 1))
+      if (key == "disabled_queries" || key == "exclude_paths") {
+        if (value !~ /^\[/) { printf "error\t%s must be an array\n", key; next }
+        value = substr(value, 2)
+        if (value ~ /\]$/) { sub(/\]$/, "", value); emit_list(key, value) }
+        else { pending = key; buffer = value }
+      } else if (key == "severity_overrides") {
+        if (value !~ /^\{.*\}$/) { printf "error\t%s must be an inline table\n", key; next }
+        emit_table(substr(value, 2, length(value) - 2))
+      } else {
+        printf "error\tunknown key %s\n", key
+      }
+    }
+  ' "$CONFIG_FILE" | jq -R -s '
+    split("\n") | map(select(length > 0) | split("\t"))
+    | (map(select(.[0] == "error") | .[1])) as $errors
+    | if ($errors | length) > 0 then error($errors | join("; ")) else . end
+    | {
+        disabled_queries: map(select(.[0] == "disabled_queries") | .[1]),
+        exclude_paths: map(select(.[0] == "exclude_paths") | .[1]),
+        severity_overrides: (map(select(.[0] == "severity_overrides") | {key: .[1], value: .[2]}) | from_entries)
+      }
+  '
+}
+
+if [ ! -f "$CONFIG_FILE" ]; then
+  if [ -n "$OUT_FILE" ]; then cp "$SARIF_FILE" "$OUT_FILE"; else cat "$SARIF_FILE"; fi
+  exit 0
+fi
+
+if ! config=$(read_config 2>&1); then
+  echo "Invalid $CONFIG_FILE: ${config#jq: error (at <stdin>:*): }" >&2
+  exit 2
+fi
+bad=$(jq -r '.severity_overrides | to_entries[] | select(.value | IN("error", "warning", "note", "recommendation") | not) | .key' <<< "$config")
+if [ -n "$bad" ]; then
+  echo "Invalid $CONFIG_FILE: severity must be error, warning, note or recommendation ($bad)" >&2
+  exit 2
+fi
+
+apply_config() {
+  jq --argjson config "$config" --arg prefix "$PREFIX" '
+    def rule_id: if startswith("cosmwasm/") then . else "cosmwasm/" + . end;
+    # Glob to anchored regex: ** spans directories, * and ? stay within one
+    def glob_regex:
+      gsub("(?<c>[.+^$(){}|\\[\\]\\\\])"; "\\\(.c)")
+      | gsub("\\*\\*/"; "\u0000") | gsub("\\*\\*"; "\u0001")
+      | gsub("\\*"; "[^/]*") | gsub("\\?"; "[^/]")
+      | gsub("\u0000"; "(.*/)?") | gsub("\u0001"; ".*")
+      | "^" + . + "$";
+
+    ($config.disabled_queries | map(rule_id)) as $disabled
+    | ($config.severity_overrides | with_entries(.key |= rule_id)
+       | map_values(if . == "recommendation" then "note" else . end)) as $overrides
+    | ($config.exclude_paths | map(glob_regex)) as $excluded
+    | def crate_path:
+        .locations[0].physicalLocation.artifactLocation.uri
+        | if $prefix == "" then . elif startswith($prefix + "/") then ltrimstr($prefix + "/") else null end;
+    .runs[].results |= map(
+      crate_path as $path
+      | if $path == null then .
+        elif (.ruleId | IN($disabled[])) then empty
+        elif any($excluded[]; . as $re | $path | test($re)) then empty
+        elif $overrides[.ruleId] then .level = $overrides[.ruleId]
+        else . end
+    )
+  ' "$SARIF_FILE"
+}
+
+if [ -n "$OUT_FILE" ]; then
+  apply_config > "$OUT_FILE"
+else
+  apply_config
+fi
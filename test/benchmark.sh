@@ -0,0 +1,220 @@
+#!/bin/bash
+# Measures how much of an analysis run is shared work, on a source tree of
+# many copies of the test fixtures.
+#
+# Usage:
+#   ./test/benchmark.sh                     # 50 copies of the vulnerable and safe fixtures
+#   ./test/benchmark.sh --copies 10         # Smaller tree
+#   ./test/benchmark.sh --synthetic 2000    # One generated contract.rs with 2000 handlers
+#   ./test/benchmark.sh --baseline HEAD~1   # Also time the queries of another revision
+#
+# Sources are parsed once, by the extractor, into the database; queries
+# never re-read them. What queries can share is the evaluation of the
+# library predicates (entry points, call graph, storage and message
+# modeling), which CodeQL caches in the database. Three timings are taken
+# over the same database:
+#
+#   per-query  one `codeql query run` per query, cache cleared before each
+#              (every query evaluates the libraries on its own)
+#   cold       one `codeql database analyze` with every query, after
+#              clearing the cache (libraries evaluated once, then shared)
+#   warm       the same analyze again, reusing the cache
+#
+# and the cold and warm runs must report the same results.
+#
+# With --baseline, the queries and libraries of that git revision are timed
+# with a cold analyze over the same database too, and must report exactly
+# the results of the working tree: a change meant to speed up evaluation
+# (such as selecting calls through the cached names of CalleeNames.qll) is
+# checked for unchanged findings and measured in one run.
+#
+# Requirements: codeql CLI, jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+PROJECT_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+BENCH_DIR="$SCRIPT_DIR/db/bench"
+
+USAGE="usage: benchmark.sh [--copies N | --synthetic N] [--baseline REV]"
+COPIES=50
+SYNTHETIC=""
+BASELINE=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --copies) COPIES="$2"; shift 2 ;;
+    --synthetic) SYNTHETIC="$2"; shift 2 ;;
+    --baseline) BASELINE="$2"; shift 2 ;;
+    *) echo "$USAGE" >&2; exit 2 ;;
+  esac
+done
+if [[ ! "$COPIES" =~ ^[1-9][0-9]*$ ]] || [[ -n "$SYNTHETIC" && ! "$SYNTHETIC" =~ ^[1-9][0-9]*$ ]]; then
+  echo "$USAGE" >&2
+  exit 2
+fi
+
+QUERIES=()
+while IFS= read -r query; do
+  QUERIES+=("$query")
+done < <(find "$PROJECT_ROOT/src/queries" -name '*.ql' | sort)
+
+# Prints the wall-clock seconds a command takes; its output is discarded
+elapsed() {
+  local TIMEFORMAT=%R
+  { time "$@" >/dev/null 2>&1; } 2>&1
+}
+
+clear_cache() {
+  codeql database cleanup --cache-cleanup=clear "$DB" >/dev/null 2>&1
+}
+
+# Analyzes the database with every query of the pack at $2 (default: the working tree)
+analyze() {
+  local pack="${2:-$PROJECT_ROOT}" queries=()
+  while IFS= read -r query; do
+    queries+=("$query")
+  done < <(find "$pack/src/queries" -name '*.ql' | sort)
+  codeql database analyze "$DB" "${queries[@]}" \
+    --additional-packs="$pack" \
+    --format=sarifv2.1.0 \
+    --output="$1" \
+    --rerun
+}
+
+per_query() {
+  local query
+  for query in "${QUERIES[@]}"; do
+    clear_cache
+    codeql query run --database="$DB" --additional-packs="$PROJECT_ROOT" "$query"
+  done
+}
+
+# Results as sorted "rule file:line" lines, for comparing runs
+result_set() {
+  jq -r '.runs[].results[]
+    | "\(.ruleId) \(.locations[0].physicalLocation.artifactLocation.uri):\(.locations[0].physicalLocation.region.startLine)"' "$1" |
+    sort
+}
+
+# Writes a crate whose contract.rs has $1 execute handlers, each with the
+# calls, storage writes, arithmetic and messages most queries look at
+write_synthetic_crate() {
+  local n="$1" dir="$2" i
+  mkdir -p "$dir/src"
+  sed "s/safe-contract/synthetic-contract/" "$SCRIPT_DIR/fixtures/safe-contract/Cargo.toml" > "$dir/Cargo.toml"
+  cp "$SCRIPT_DIR/fixtures/admin-enforced-contract/src/lib.rs" "$SCRIPT_DIR/fixtures/admin-enforced-contract/src/error.rs" "$dir/src/"
+  {
+    cat <<'RUST'
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, entry_point, to_json_binary, BankMsg, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    Uint128,
+};
+use cw_storage_plus::Map;
+use cw_utils::must_pay;
+
+use crate::error::ContractError;
+
+pub const TOTALS: Map<u64, Uint128> = Map::new("totals");
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Handle { id: u64, amount: Uint128 },
+}
+
+#[entry_point]
+pub fn instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: InstantiateMsg) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+#[entry_point]
+pub fn execute(deps: DepsMut, _env: Env, info: MessageInfo, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Handle { id, amount } => match id {
+RUST
+    for i in $(seq 1 "$n"); do
+      echo "            $i => handler_$i(deps, info, amount),"
+    done
+    cat <<'RUST'
+            _ => Err(ContractError::Unauthorized {}),
+        },
+    }
+}
+RUST
+    for i in $(seq 1 "$n"); do
+      cat <<RUST
+
+fn handler_$i(deps: DepsMut, info: MessageInfo, amount: Uint128) -> Result<Response, ContractError> {
+    let paid = must_pay(&info, "uatom").map_err(|_| ContractError::Unauthorized {})?;
+    let total = Uint128::new($i).checked_add(paid.checked_add(amount).map_err(StdError::from)?).map_err(StdError::from)?;
+    TOTALS.save(deps.storage, $i, &total)?;
+    let msg = BankMsg::Send { to_address: info.sender.to_string(), amount: coins(total.u128(), "uatom") };
+    Ok(Response::new().add_message(msg).set_data(to_json_binary(&total)?))
+}
+RUST
+    done
+  } > "$dir/src/contract.rs"
+}
+
+if [ -n "$SYNTHETIC" ]; then
+  TREE_NAME="synthetic-$SYNTHETIC"
+  TREE_DESC="one contract.rs with $SYNTHETIC handlers"
+else
+  TREE_NAME="$COPIES"
+  TREE_DESC="$COPIES copies"
+fi
+SOURCE_TREE="$BENCH_DIR/src-$TREE_NAME"
+DB="$BENCH_DIR/db-$TREE_NAME"
+if [ ! -d "$DB" ] && [ -n "$SYNTHETIC" ]; then
+  echo "Generating a contract with $SYNTHETIC handlers ..."
+  rm -rf "$SOURCE_TREE"
+  write_synthetic_crate "$SYNTHETIC" "$SOURCE_TREE/synthetic-contract"
+  codeql database create "$DB" --language=rust --source-root="$SOURCE_TREE" --overwrite 2>&1 | tail -1
+elif [ ! -d "$DB" ]; then
+  echo "Building a tree of $COPIES copies of the fixtures ..."
+  rm -rf "$SOURCE_TREE"
+  for i in $(seq -w 1 "$COPIES"); do
+    for fixture in vulnerable-contract safe-contract; do
+      mkdir -p "$SOURCE_TREE/copy-$i"
+      cp -R "$SCRIPT_DIR/fixtures/$fixture" "$SOURCE_TREE/copy-$i/$fixture"
+    done
+  done
+  codeql database create "$DB" --language=rust --source-root="$SOURCE_TREE" --overwrite 2>&1 | tail -1
+fi
+
+echo "=== Benchmark: $TREE_DESC, ${#QUERIES[@]} queries ==="
+per_query_time=$(elapsed per_query)
+clear_cache
+cold_time=$(elapsed analyze "$BENCH_DIR/cold.sarif")
+warm_time=$(elapsed analyze "$BENCH_DIR/warm.sarif")
+
+printf '%-10s %8ss\n' "per-query" "$per_query_time" "cold" "$cold_time" "warm" "$warm_time"
+
+if [ "$(result_set "$BENCH_DIR/cold.sarif")" = "$(result_set "$BENCH_DIR/warm.sarif")" ]; then
+  echo "cold and warm runs report the same $(jq '[.runs[].results[]] | length' "$BENCH_DIR/warm.sarif") results"
+else
+  echo "cold and warm runs report different results" >&2
+  exit 1
+fi
+
+if [ -n "$BASELINE" ]; then
+  BASELINE_SHA=$(git -C "$PROJECT_ROOT" rev-parse --short "$BASELINE^{commit}")
+  BASELINE_PACK="$BENCH_DIR/baseline-$BASELINE_SHA"
+  if [ ! -d "$BASELINE_PACK" ]; then
+    mkdir -p "$BASELINE_PACK"
+    git -C "$PROJECT_ROOT" archive "$BASELINE_SHA" qlpack.yml codeql-pack.lock.yml src ext |
+      tar -x -C "$BASELINE_PACK"
+  fi
+  clear_cache
+  baseline_time=$(elapsed analyze "$BENCH_DIR/baseline.sarif" "$BASELINE_PACK")
+  printf '%-10s %8ss  (cold, queries of %s)\n' "baseline" "$baseline_time" "$BASELINE_SHA"
+  if [ "$(result_set "$BENCH_DIR/baseline.sarif")" = "$(result_set "$BENCH_DIR/cold.sarif")" ]; then
+    echo "baseline $BASELINE_SHA and the working tree report the same results"
+  else
+    echo "baseline $BASELINE_SHA and the working tree report different results:" >&2
+    diff <(result_set "$BENCH_DIR/baseline.sarif") <(result_set "$BENCH_DIR/cold.sarif") >&2 || true
+    exit 1
+  fi
+fi
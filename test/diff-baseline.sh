@@ -0,0 +1,103 @@
+#!/bin/bash
+# Compares a SARIF run against a stored baseline so only new findings are
+# reported, letting large codebases adopt the pack without triaging every
+# pre-existing issue first.
+#
+# Usage:
+#   ./test/diff-baseline.sh <source-root> <results.sarif> --write-baseline <file>
+#   ./test/diff-baseline.sh <source-root> <results.sarif> --baseline <file>
+#
+# A finding is identified by its rule id, file and the whitespace-normalized
+# source line it starts on, not by line number, so code shifting up or down
+# does not turn known findings into new ones. With --baseline, prints one
+# tab-separated line per new finding (rule id, severity, confidence,
+# file:line, message) and exits 1 if there are any. Suppressed results are
+# ignored.
+#
+# Requirements: jq, sha256sum (or shasum)
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+
+USAGE="usage: diff-baseline.sh <source-root> <results.sarif> (--baseline FILE | --write-baseline FILE)"
+SOURCE_ROOT=""
+SARIF_FILE=""
+BASELINE=""
+WRITE_BASELINE=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --baseline) BASELINE="$2"; shift 2 ;;
+    --write-baseline) WRITE_BASELINE="$2"; shift 2 ;;
+    *)
+      if [ -z "$SOURCE_ROOT" ]; then SOURCE_ROOT="$1"; else SARIF_FILE="$1"; fi
+      shift
+      ;;
+  esac
+done
+
+if [ -z "$SOURCE_ROOT" ] || [ ! -f "$SARIF_FILE" ] || { [ -z "$BASELINE" ] && [ -z "$WRITE_BASELINE" ]; }; then
+  echo "$USAGE" >&2
+  exit 2
+fi
+
+sha256() {
+  if command -v sha256sum >/dev/null 2>&1; then sha256sum; else shasum -a 256; fi | cut -d' ' -f1
+}
+
+# Prints a JSON array of {fingerprint, ruleId, severity, confidence, file,
+# line, message}, one per unsuppressed result, sorted by location.
+fingerprint_results() {
+  jq -r --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" '
+    ([$registry[], .runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?]
+     | map({key: .id, value: .}) | from_entries) as $rules
+    | .runs[].results[]
+    | select((.suppressions // []) | length == 0)
+    | .locations[0].physicalLocation as $loc
+    | [.ruleId, (.level // $rules[.ruleId].defaultConfiguration.level // "warning"),
+       ($rules[.ruleId].properties.precision // "unknown"),
+       $loc.artifactLocation.uri, $loc.region.startLine, .message.text]
+    | @tsv
+  ' "$SARIF_FILE" |
+    while IFS=$'\t' read -r rule severity confidence uri line message; do
+      snippet=$(sed -n "${line}p" "$SOURCE_ROOT/$uri" 2>/dev/null | tr -s '[:space:]' ' ' | sed 's/^ //; s/ $//')
+      fingerprint=$(printf '%s\n%s\n%s' "$rule" "$uri" "$snippet" | sha256)
+      jq -cn --arg fp "$fingerprint" --arg rule "$rule" --arg severity "$severity" --arg confidence "$confidence" \
+        --arg uri "$uri" --argjson line "$line" --arg msg "$message" \
+        '{fingerprint: $fp, ruleId: $rule, severity: $severity, confidence: $confidence,
+          file: $uri, line: $line, message: $msg}'
+    done | jq -s 'sort_by(.file, .line)'
+}
+
+# Prints the findings of <current> (JSON array) not covered by the baseline
+# file. Fingerprints are compared as a multiset: two identical lines with the
+# same finding need two baseline entries.
+diff_against_baseline() {
+  local current="$1" baseline_file="$2"
+  jq -n --argjson cur "$current" --slurpfile base "$baseline_file" '
+    ($base[0].findings | group_by(.fingerprint) | map({key: .[0].fingerprint, value: length}) | from_entries)
+      as $known
+    | $cur
+    | group_by(.fingerprint)
+    | map(.[($known[.[0].fingerprint] // 0):][])
+    | sort_by(.file, .line)
+  '
+}
+
+current=$(fingerprint_results)
+
+if [ -n "$WRITE_BASELINE" ]; then
+  jq -n --argjson cur "$current" '{version: 1, findings: $cur}' > "$WRITE_BASELINE"
+  echo "Wrote $(jq length <<< "$current") findings to $WRITE_BASELINE" >&2
+fi
+
+if [ -n "$BASELINE" ]; then
+  if [ ! -f "$BASELINE" ]; then
+    echo "Baseline not found: $BASELINE" >&2
+    exit 2
+  fi
+  new=$(diff_against_baseline "$current" "$BASELINE")
+  new_count=$(jq length <<< "$new")
+  jq -r '.[] | [.ruleId, .severity, .confidence, "\(.file):\(.line)", .message] | @tsv' <<< "$new"
+  echo "$new_count new, $(($(jq length <<< "$current") - new_count)) in baseline" >&2
+  [ "$new_count" -eq 0 ] || exit 1
+fi
@@ -19,7 +19,7 @@ DB_DIR="$SCRIPT_DIR/db"
 RESULTS_DIR="$SCRIPT_DIR/results"
 TARGETS_CONF="$SCRIPT_DIR/targets.conf"
 
-# All 10 queries
+# All queries
 QUERIES=(
   "src/queries/access-control/MissingExecuteAuthorization.ql"
   "src/queries/access-control/MissingMigrateAuthorization.ql"
@@ -31,6 +31,76 @@ QUERIES=(
   "src/queries/cross-contract/IbcCeiViolation.ql"
   "src/queries/cross-contract/SubmsgWithoutReplyHandler.ql"
   "src/queries/cross-contract/ReplyHandlerIgnoringErrors.ql"
+  "src/queries/cross-contract/ReentrancyViaReply.ql"
+  "src/queries/data-safety/MissingMigrateVersionCheck.ql"
+  "src/queries/denial-of-service/UnboundedMapIteration.ql"
+  "src/queries/determinism/FloatUsage.ql"
+  "src/queries/data-safety/UnvalidatedFunds.ql"
+  "src/queries/data-safety/LossyIntegerCast.ql"
+  "src/queries/determinism/NondeterministicIteration.ql"
+  "src/queries/determinism/BlockDataAsRandomness.ql"
+  "src/queries/cross-contract/SendToUnvalidatedAddress.ql"
+  "src/queries/data-safety/DivisionByZero.ql"
+  "src/queries/data-safety/MissingZeroAmountCheck.ql"
+  "src/queries/access-control/NoPauseMechanism.ql"
+  "src/queries/denial-of-service/ExplicitPanic.ql"
+  "src/queries/denial-of-service/PanickingIndex.ql"
+  "src/queries/cross-contract/UntrustedQueryResult.ql"
+  "src/queries/cross-contract/MissingIbcHandler.ql"
+  "src/queries/data-safety/MissingCw2Init.ql"
+  "src/queries/data-safety/HardcodedAddress.ql"
+  "src/queries/data-safety/HardcodedCoin.ql"
+  "src/queries/data-safety/UnexpectedFunds.ql"
+  "src/queries/data-safety/MissingStatusGate.ql"
+  "src/queries/access-control/SingleStepAdminTransfer.ql"
+  "src/queries/cross-contract/IbcHandshakeUnvalidated.ql"
+  "src/queries/cross-contract/IbcRefundTrust.ql"
+  "src/queries/denial-of-service/UnboundedInput.ql"
+  "src/queries/data-safety/MutationInQuery.ql"
+  "src/queries/data-safety/NoEventsEmitted.ql"
+  "src/queries/cross-contract/ReplyIdCollision.ql"
+  "src/queries/data-safety/DecimalConversionHazard.ql"
+  "src/queries/cross-contract/InstantiateNoAdmin.ql"
+  "src/queries/data-safety/SaturatingArithmetic.ql"
+  "src/queries/data-safety/UncheckedDepositDenom.ql"
+  "src/queries/cross-contract/UncheckedReplyData.ql"
+  "src/queries/access-control/UnusedAdminField.ql"
+  "src/queries/denial-of-service/UnboundedStorageLoop.ql"
+  "src/queries/access-control/MissingSelfCallGuard.ql"
+  "src/queries/access-control/Cw20ReceiveUnvalidated.ql"
+  "src/queries/data-safety/TimestampUnitMismatch.ql"
+  "src/queries/cross-contract/DroppedMessage.ql"
+  "src/queries/access-control/SudoSharedHelper.ql"
+  "src/queries/denial-of-service/UnboundedPagination.ql"
+  "src/queries/data-safety/MissingEntryPointAttr.ql"
+  "src/queries/cross-contract/InvalidCoinList.ql"
+  "src/queries/data-safety/IgnoredResult.ql"
+  "src/queries/data-safety/LoopAccumulatorOverflow.ql"
+  "src/queries/data-safety/SelfTransferHazard.ql"
+  "src/queries/denial-of-service/PanickingUpdateClosure.ql"
+  "src/queries/access-control/BlindOverwrite.ql"
+  "src/queries/denial-of-service/QueryInLoop.ql"
+  "src/queries/data-safety/MigrateMsgNoVersion.ql"
+  "src/queries/cross-contract/ReplyCatchAllOk.ql"
+  "src/queries/data-safety/SuspiciousAmountLiteral.ql"
+  "src/queries/data-safety/RawStorageAccess.ql"
+  "src/queries/data-safety/StringAddressInState.ql"
+  "src/queries/cross-contract/CrossMapConsistency.ql"
+  "src/queries/data-safety/IncompleteInit.ql"
+  "src/queries/data-safety/UnnormalizedMapKey.ql"
+  "src/queries/data-safety/GenericErrorOveruse.ql"
+  "src/queries/cross-contract/IbcAckMismatch.ql"
+  "src/queries/data-safety/ExpiryBoundary.ql"
+  "src/queries/data-safety/InconsistentPayability.ql"
+  "src/queries/data-safety/UnboundedAmountParse.ql"
+  "src/queries/data-safety/QueryResponseUntyped.ql"
+  "src/queries/data-safety/SwallowedError.ql"
+  "src/queries/cross-contract/UntrustedQueryAddress.ql"
+  "src/queries/access-control/SenderTypeAssumption.ql"
+  "src/queries/denial-of-service/UncheckedPaginationCursor.ql"
+  "src/queries/data-safety/ErrorTypeBypass.ql"
+  "src/queries/denial-of-service/UnboundedSelfRecursion.ql"
+  "src/queries/denial-of-service/BinarySerializationUnwrap.ql"
 )
 
 green() { printf "\033[32m%s\033[0m" "$1"; }
@@ -126,6 +196,9 @@ run_queries() {
   local target_results="$RESULTS_DIR/$name"
   mkdir -p "$target_results"
 
+  # Release overflow-checks per crate (Cargo.toml is not in the database)
+  bash "$PROJECT_ROOT/test/overflow-checks.sh" "$TARGETS_DIR/$name" 2>/dev/null
+
   local summary_file="$target_results/summary.txt"
   echo "CosmWasm CodeQL E2E Results — $(date)" > "$summary_file"
   echo "Target: $name" >> "$summary_file"
@@ -0,0 +1,73 @@
+#!/bin/bash
+# Lists a contract's `#[entry_point]` functions against the standard
+# CosmWasm handlers, for the header of the Markdown and HTML reports.
+#
+# Usage:
+#   ./test/entry-points.sh <source-root>
+#
+# Prints one tab-separated line per handler: name, `present` or `missing`,
+# and file:line of the function when present. The six core handlers
+# (instantiate, execute, query, migrate, reply, sudo) are always listed;
+# the six IBC channel and packet handlers only when the contract defines at
+# least one `ibc_*` entry point. Other entry points (e.g. IBC callbacks)
+# follow as present. Files under tests/ and target/ are skipped. Prints
+# nothing when the crate has no entry points.
+#
+# Missing IBC companions (`ibc_packet_receive` without `ibc_packet_ack` or
+# `ibc_packet_timeout`, ...) are reported by the cosmwasm/missing-ibc-handler
+# query, not here.
+set -eo pipefail
+
+SOURCE_ROOT="$1"
+if [ -z "$SOURCE_ROOT" ] || [ ! -d "$SOURCE_ROOT" ]; then
+  echo "usage: entry-points.sh <source-root>" >&2
+  exit 2
+fi
+
+CORE_HANDLERS=(instantiate execute query migrate reply sudo)
+IBC_HANDLERS=(
+  ibc_channel_open ibc_channel_connect ibc_channel_close
+  ibc_packet_receive ibc_packet_ack ibc_packet_timeout
+)
+
+# Prints "name<TAB>file:line" for each function annotated with
+# #[entry_point], #[cosmwasm_std::entry_point] or
+# #[cfg_attr(..., entry_point)], with paths relative to the source root.
+find_entry_points() {
+  (cd "$SOURCE_ROOT" && find . -name '*.rs' -not -path '*/target/*' -not -path '*/tests/*' | sed 's|^\./||' | sort) |
+    while IFS= read -r file; do
+      awk -v file="$file" '
+        /^[[:space:]]*#\[(cosmwasm_std::)?entry_point\]/ || /^[[:space:]]*#\[cfg_attr\(.*entry_point\)\]/ {
+          pending = 1
+          next
+        }
+        pending && match($0, /fn[[:space:]]+[A-Za-z_][A-Za-z0-9_]*/) {
+          name = substr($0, RSTART, RLENGTH)
+          sub(/^fn[[:space:]]+/, "", name)
+          printf "%s\t%s:%d\n", name, file, NR
+          pending = 0
+        }
+      ' "$SOURCE_ROOT/$file"
+    done
+}
+
+found=$(find_entry_points)
+[ -n "$found" ] || exit 0
+
+handlers=("${CORE_HANDLERS[@]}")
+if printf '%s\n' "$found" | cut -f1 | grep -q '^ibc_'; then
+  handlers+=("${IBC_HANDLERS[@]}")
+fi
+
+for handler in "${handlers[@]}"; do
+  location=$(printf '%s\n' "$found" | awk -F'\t' -v h="$handler" '$1 == h { print $2; exit }')
+  if [ -n "$location" ]; then
+    printf '%s\tpresent\t%s\n' "$handler" "$location"
+  else
+    printf '%s\tmissing\t\n' "$handler"
+  fi
+done
+
+printf '%s\n' "$found" | while IFS=$'\t' read -r name location; do
+  [[ " ${handlers[*]} " == *" $name "* ]] || printf '%s\tpresent\t%s\n' "$name" "$location"
+done
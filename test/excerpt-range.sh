@@ -0,0 +1,26 @@
+#!/bin/bash
+# Computes the lines of the three-line code excerpt around a finding, for
+# the Markdown and HTML reports.
+#
+# Usage:
+#   ./test/excerpt-range.sh <file> <line>
+#
+# Prints the first and last line of the excerpt, tab-separated: the line
+# before <line> through the line after it, clamped to the file. A final
+# line without a trailing newline counts. Prints nothing when <file> does
+# not exist or has fewer than <line> lines.
+set -eo pipefail
+
+FILE="$1"
+LINE="$2"
+if [ -z "$FILE" ] || [[ ! "$LINE" =~ ^[0-9]+$ ]]; then
+  echo "usage: excerpt-range.sh <file> <line>" >&2
+  exit 2
+fi
+[ -f "$FILE" ] || exit 0
+
+total=$(wc -l < "$FILE" | tr -d ' ')
+# Count a final line without a trailing newline
+[ -n "$(tail -c 1 "$FILE")" ] && total=$((total + 1))
+[ "$LINE" -le "$total" ] || exit 0
+printf '%s\t%s\n' "$((LINE > 1 ? LINE - 1 : 1))" "$((LINE < total ? LINE + 1 : total))"
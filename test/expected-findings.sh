@@ -0,0 +1,76 @@
+#!/bin/bash
+# Compares the findings of a fixture run with the fixture's expected.json, so
+# the `// Qn:` comments in the fixture sources are checked line by line: every
+# expected finding must be reported and nothing else may be.
+#
+# Usage:
+#   ./test/expected-findings.sh <fixture-dir> <results.sarif>
+#   ./test/expected-findings.sh --update <fixture-dir> <results.sarif>
+#
+# The SARIF file comes from `codeql database analyze` over the fixture's
+# database with every query in the rule registry. expected.json lists one
+# object per finding, sorted by file, line and query:
+#
+#   [
+#     {"query":"cosmwasm/missing-cw2-version","file":"src/contract.rs","line":14}
+#   ]
+#
+# A query that reports two results on one line is listed twice. Results
+# suppressed in source are not findings. Prints one "missing" or "unexpected"
+# line per difference and exits 1 when there is any; --update rewrites
+# expected.json from the run instead (review the diff before committing it).
+#
+# Requirements: jq
+set -eo pipefail
+
+UPDATE=false
+if [ "${1:-}" = "--update" ]; then
+  UPDATE=true
+  shift
+fi
+FIXTURE_DIR="${1:?usage: expected-findings.sh [--update] <fixture-dir> <results.sarif>}"
+SARIF_FILE="${2:?usage: expected-findings.sh [--update] <fixture-dir> <results.sarif>}"
+EXPECTED_FILE="$FIXTURE_DIR/expected.json"
+
+# "query<TAB>file<TAB>line" for every unsuppressed result, sorted
+actual=$(jq -r '
+  .runs[].results[]
+  | select((.suppressions // []) | length == 0)
+  | .locations[0].physicalLocation as $loc
+  | [.ruleId, $loc.artifactLocation.uri, $loc.region.startLine] | @tsv
+' "$SARIF_FILE" | sort -t$'\t' -k2,2 -k3,3n -k1,1)
+
+if $UPDATE; then
+  {
+    echo "["
+    if [ -n "$actual" ]; then
+      printf '%s\n' "$actual" |
+        jq -R -c 'split("\t") | {query: .[0], file: .[1], line: (.[2] | tonumber)}' |
+        sed '$!s/$/,/; s/^/  /'
+    fi
+    echo "]"
+  } > "$EXPECTED_FILE"
+  echo "Wrote $EXPECTED_FILE ($(printf '%s' "$actual" | grep -c . || true) findings)"
+  exit 0
+fi
+
+if [ ! -f "$EXPECTED_FILE" ]; then
+  echo "error: $EXPECTED_FILE not found" >&2
+  exit 2
+fi
+
+expected=$(jq -r '.[] | [.query, .file, .line] | @tsv' "$EXPECTED_FILE" |
+  sort -t$'\t' -k2,2 -k3,3n -k1,1)
+
+status=0
+while IFS=$'\t' read -r query file line; do
+  [ -z "$query" ] && continue
+  echo "missing: $query at $file:$line"
+  status=1
+done < <(comm -23 <(printf '%s\n' "$expected" | sort) <(printf '%s\n' "$actual" | sort))
+while IFS=$'\t' read -r query file line; do
+  [ -z "$query" ] && continue
+  echo "unexpected: $query at $file:$line"
+  status=1
+done < <(comm -13 <(printf '%s\n' "$expected" | sort) <(printf '%s\n' "$actual" | sort))
+exit $status
@@ -0,0 +1,51 @@
+#!/bin/bash
+# Sets the exit code of a CI step from SARIF results: fails when any
+# unsuppressed result is at or above the given severity
+# (error > warning > note).
+#
+# Usage:
+#   ./test/fail-on.sh <results.sarif> --fail-on error    # Only errors fail
+#   ./test/fail-on.sh <results.sarif> --fail-on warning  # Warnings and errors fail
+#
+# Severity is the rule's @problem.severity (SARIF level; `recommendation`
+# is `note`) and confidence its @precision. Prints one tab-separated line
+# per failing result: rule id, severity, confidence, file:line, message.
+# Exits 1 if there are any.
+#
+# Requirements: jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+
+SARIF_FILE=""
+FAIL_ON=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --fail-on) FAIL_ON="$2"; shift 2 ;;
+    *) SARIF_FILE="$1"; shift ;;
+  esac
+done
+
+if [ ! -f "$SARIF_FILE" ] || [[ ! "$FAIL_ON" =~ ^(error|warning|note)$ ]]; then
+  echo "usage: fail-on.sh <results.sarif> --fail-on error|warning|note" >&2
+  exit 2
+fi
+
+failing=$(jq -r --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" --arg fail_on "$FAIL_ON" '
+  def rank: {"error": 3, "warning": 2, "note": 1, "none": 0}[.] // 2;
+  [$registry[], .runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?] as $rules
+  | ($rules | map({key: .id, value: .}) | from_entries) as $by_id
+  | .runs[].results[]
+  | select((.suppressions // []) | length == 0)
+  | (.level // $by_id[.ruleId].defaultConfiguration.level // "warning") as $severity
+  | select(($severity | rank) >= ($fail_on | rank))
+  | .locations[0].physicalLocation as $loc
+  | [.ruleId, $severity, ($by_id[.ruleId].properties.precision // "unknown"),
+     "\($loc.artifactLocation.uri):\($loc.region.startLine)", .message.text]
+  | @tsv
+' "$SARIF_FILE")
+
+count=$(printf '%s\n' "$failing" | grep -c . || true)
+[ -n "$failing" ] && echo "$failing"
+echo "$count result(s) at or above $FAIL_ON" >&2
+[ "$count" -eq 0 ]
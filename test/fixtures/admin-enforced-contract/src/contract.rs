@@ -0,0 +1,75 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+#[cw_serde]
+pub struct Config {
+    pub owner: Addr,
+    pub fee_bps: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const TOTAL_SUPPLY: Item<Uint128> = Item::new("total_supply");
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    SetFee { fee_bps: u64 },
+    Mint { amount: Uint128 },
+}
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    CONFIG.save(deps.storage, &Config { owner: info.sender, fee_bps: 30 })?;
+    Ok(Response::new())
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::SetFee { fee_bps } => execute_set_fee(deps, info, fee_bps),
+        ExecuteMsg::Mint { amount } => execute_mint(deps, info, amount),
+    }
+}
+
+// Safe: the stored owner is compared to info.sender inline
+fn execute_set_fee(deps: DepsMut, info: MessageInfo, fee_bps: u64) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    config.fee_bps = fee_bps;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new().add_attribute("action", "set_fee"))
+}
+
+// Safe: the stored owner is consulted through a helper
+fn execute_mint(deps: DepsMut, info: MessageInfo, amount: Uint128) -> Result<Response, ContractError> {
+    ensure_owner(deps.as_ref(), &info.sender)?;
+    let supply = TOTAL_SUPPLY.may_load(deps.storage)?.unwrap_or_default();
+    TOTAL_SUPPLY.save(deps.storage, &supply.checked_add(amount).map_err(cosmwasm_std::StdError::from)?)?;
+    Ok(Response::new().add_attribute("action", "mint"))
+}
+
+fn ensure_owner(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if *sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, Addr, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_storage_plus::{Item, Map};
+use crate::error::ContractError;
+
+pub const MAX_BATCH_SIZE: usize = 50;
+pub const MAX_NAME_LENGTH: usize = 64;
+
+pub const MINTER: Item<Addr> = Item::new("minter");
+pub const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
+pub const MEMOS: Map<&Addr, String> = Map::new("memos");
+pub const NAMES: Map<&Addr, String> = Map::new("names");
+
+#[cw_serde]
+pub struct Transfer {
+    pub recipient: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    BatchMint { recipients: Vec<String>, amount: Uint128 },
+    BatchTransfer { transfers: Vec<Transfer> },
+    SetMemo { memo: String },
+    SetName { name: String },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::BatchMint { recipients, amount } => {
+            execute_batch_mint(deps, info, recipients, amount)
+        }
+        ExecuteMsg::BatchTransfer { transfers } => execute_batch_transfer(deps, info, transfers),
+        ExecuteMsg::SetMemo { memo } => execute_set_memo(deps, info, memo),
+        ExecuteMsg::SetName { name } => execute_set_name(deps, info, name),
+    }
+}
+
+fn execute_batch_mint(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipients: Vec<String>,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if info.sender != MINTER.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Q36: Unbounded input — the minter's batch is iterated whatever its size
+    for recipient in recipients {
+        let addr = deps.api.addr_validate(&recipient)?;
+        BALANCES.update(deps.storage, &addr, |bal| -> Result<_, ContractError> {
+            Ok(bal.unwrap_or_default().checked_add(amount)?)
+        })?;
+    }
+    Ok(Response::new())
+}
+
+// Safe: the batch size is capped before the loop
+fn execute_batch_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    transfers: Vec<Transfer>,
+) -> Result<Response, ContractError> {
+    if transfers.len() > MAX_BATCH_SIZE {
+        return Err(ContractError::BatchTooLarge {
+            size: transfers.len(),
+            max: MAX_BATCH_SIZE,
+        });
+    }
+    for transfer in transfers.iter() {
+        let addr = deps.api.addr_validate(&transfer.recipient)?;
+        BALANCES.update(deps.storage, &info.sender, |bal| -> Result<_, ContractError> {
+            Ok(bal.unwrap_or_default().checked_sub(transfer.amount)?)
+        })?;
+        BALANCES.update(deps.storage, &addr, |bal| -> Result<_, ContractError> {
+            Ok(bal.unwrap_or_default().checked_add(transfer.amount)?)
+        })?;
+    }
+    Ok(Response::new())
+}
+
+// Q36: Unbounded input — a memo of any length is written to storage
+fn execute_set_memo(
+    deps: DepsMut,
+    info: MessageInfo,
+    memo: String,
+) -> Result<Response, ContractError> {
+    MEMOS.save(deps.storage, &info.sender, &memo)?;
+    Ok(Response::new())
+}
+
+// Safe: the name length is capped before it is stored
+fn execute_set_name(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    if name.len() > MAX_NAME_LENGTH {
+        return Err(ContractError::NameTooLong { max: MAX_NAME_LENGTH });
+    }
+    NAMES.save(deps.storage, &info.sender, &name)?;
+    Ok(Response::new())
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Batch of {size} exceeds the maximum of {max}")]
+    BatchTooLarge { size: usize, max: usize },
+
+    #[error("Name is longer than {max} bytes")]
+    NameTooLong { max: usize },
+}
@@ -0,0 +1,102 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, entry_point, to_json_binary, Addr, BankMsg, CosmosMsg, DepsMut, Env, MessageInfo,
+    Response, SubMsg, Uint128, WasmMsg,
+};
+use cw_storage_plus::Map;
+use crate::error::ContractError;
+
+pub const DENOM: &str = "uatom";
+
+pub const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Withdraw { amount: Uint128 },
+    Notify { hook: String },
+    Claim { amount: Uint128 },
+    Split { recipients: Vec<String>, amount: Uint128 },
+}
+
+#[cw_serde]
+pub enum HookMsg {
+    Ping {},
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Withdraw { amount } => execute_withdraw(deps, info, amount),
+        ExecuteMsg::Notify { hook } => execute_notify(deps, hook),
+        ExecuteMsg::Claim { amount } => execute_claim(deps, info, amount),
+        ExecuteMsg::Split { recipients, amount } => execute_split(recipients, amount),
+    }
+}
+
+// Q49: Dropped message — the balance is debited but the refund is never sent
+fn execute_withdraw(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    BALANCES.update(deps.storage, &info.sender, |bal| -> Result<_, ContractError> {
+        Ok(bal.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    let refund = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), DENOM),
+    };
+    Ok(Response::new().add_attribute("action", "withdraw"))
+}
+
+// Q49: Dropped message — the submessage wrapping the hook call is never added
+fn execute_notify(deps: DepsMut, hook: String) -> Result<Response, ContractError> {
+    let hook_addr = deps.api.addr_validate(&hook)?;
+    let ping = WasmMsg::Execute {
+        contract_addr: hook_addr.to_string(),
+        msg: to_json_binary(&HookMsg::Ping {})?,
+        funds: vec![],
+    };
+    let sub = SubMsg::new(ping);
+    Ok(Response::new().add_attribute("action", "notify"))
+}
+
+// Safe: the payout is attached to the response
+fn execute_claim(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    BALANCES.update(deps.storage, &info.sender, |bal| -> Result<_, ContractError> {
+        Ok(bal.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    let payout = payout_msg(&info.sender, amount);
+    Ok(Response::new().add_message(payout))
+}
+
+// Safe: every message is collected and added at once
+fn execute_split(recipients: Vec<String>, amount: Uint128) -> Result<Response, ContractError> {
+    let mut msgs = vec![];
+    for recipient in recipients {
+        let msg = BankMsg::Send {
+            to_address: recipient,
+            amount: coins(amount.u128(), DENOM),
+        };
+        msgs.push(msg);
+    }
+    Ok(Response::new().add_messages(msgs))
+}
+
+// Safe: the message is returned to the caller
+fn payout_msg(recipient: &Addr, amount: Uint128) -> CosmosMsg {
+    let msg = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: coins(amount.u128(), DENOM),
+    };
+    msg.into()
+}
@@ -0,0 +1,77 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, Addr, DepsMut, Env, Event, MessageInfo, Response, Uint128};
+use cw_storage_plus::{Item, Map};
+use crate::error::ContractError;
+
+pub const MINTER: Item<Addr> = Item::new("minter");
+pub const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Mint { recipient: String, amount: Uint128 },
+    Burn { amount: Uint128 },
+    SetMinter { minter: String },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Mint { recipient, amount } => execute_mint(deps, info, recipient, amount),
+        ExecuteMsg::Burn { amount } => execute_burn(deps, info, amount),
+        ExecuteMsg::SetMinter { minter } => execute_set_minter(deps, info, minter),
+    }
+}
+
+// Safe: the response records the action and its parameters
+fn execute_mint(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if info.sender != MINTER.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    let addr = deps.api.addr_validate(&recipient)?;
+    BALANCES.update(deps.storage, &addr, |bal| -> Result<_, ContractError> {
+        Ok(bal.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "mint")
+        .add_attribute("recipient", addr)
+        .add_attribute("amount", amount))
+}
+
+// Safe: emits a typed event
+fn execute_burn(deps: DepsMut, info: MessageInfo, amount: Uint128) -> Result<Response, ContractError> {
+    let balance = BALANCES.load(deps.storage, &info.sender)?;
+    BALANCES.save(deps.storage, &info.sender, &balance.checked_sub(amount)?)?;
+    Ok(Response::new().add_event(
+        Event::new("burn")
+            .add_attribute("owner", info.sender)
+            .add_attribute("amount", amount),
+    ))
+}
+
+// Safe: the response is built by a shared helper that adds the attributes
+fn execute_set_minter(
+    deps: DepsMut,
+    info: MessageInfo,
+    minter: String,
+) -> Result<Response, ContractError> {
+    if info.sender != MINTER.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    let minter = deps.api.addr_validate(&minter)?;
+    MINTER.save(deps.storage, &minter)?;
+    Ok(action_response("set_minter"))
+}
+
+fn action_response(action: &str) -> Response {
+    Response::new().add_attribute("action", action)
+}
@@ -0,0 +1,37 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_storage_plus::Map;
+use crate::error::ContractError;
+
+pub const STAKES: Map<&str, Uint128> = Map::new("stakes");
+
+pub const DENOM: &str = "ustake";
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Stake {},
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Stake {} => execute_stake(deps, info),
+    }
+}
+
+// Q81: the coin is credited first and its denom checked only afterwards
+fn execute_stake(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let coin = info.funds.first().ok_or(ContractError::NoFunds {})?;
+    STAKES.update(deps.storage, info.sender.as_str(), |stake| -> Result<_, ContractError> {
+        Ok(stake.unwrap_or_default().checked_add(coin.amount).map_err(cosmwasm_std::StdError::from)?)
+    })?;
+    if coin.denom != DENOM {
+        return Err(ContractError::WrongDenom {});
+    }
+    Ok(Response::new().add_attribute("action", "stake"))
+}
@@ -0,0 +1,49 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_storage_plus::{Item, Map};
+use crate::error::ContractError;
+
+pub const STAKES: Map<&str, Uint128> = Map::new("stakes");
+pub const PINGS: Item<u64> = Item::new("pings");
+
+pub const DENOM: &str = "ustake";
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Stake {},
+    Ping {},
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Stake {} => execute_stake(deps, info),
+        ExecuteMsg::Ping {} => execute_ping(deps, info),
+    }
+}
+
+// Safe: the denom is checked before the coin is credited
+fn execute_stake(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let coin = info.funds.first().ok_or(ContractError::NoFunds {})?;
+    if coin.denom != DENOM {
+        return Err(ContractError::WrongDenom {});
+    }
+    STAKES.update(deps.storage, info.sender.as_str(), |stake| -> Result<_, ContractError> {
+        Ok(stake.unwrap_or_default().checked_add(coin.amount).map_err(cosmwasm_std::StdError::from)?)
+    })?;
+    Ok(Response::new().add_attribute("action", "stake"))
+}
+
+// Safe: the funds are only listed in an attribute; the arithmetic is on an unrelated counter
+fn execute_ping(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let denoms: Vec<String> = info.funds.iter().map(|coin| coin.denom.clone()).collect();
+    PINGS.update(deps.storage, |pings| -> Result<_, ContractError> { Ok(pings + 1) })?;
+    Ok(Response::new()
+        .add_attribute("action", "ping")
+        .add_attribute("denoms", denoms.join(",")))
+}
@@ -0,0 +1,66 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, entry_point, Addr, BankMsg, DepsMut, Env, MessageInfo, Response,
+};
+use cw_storage_plus::Item;
+use cw_utils::must_pay;
+use crate::error::ContractError;
+
+// Q29: Hardcoded address — the fee collector is a bech32 literal, so fees go
+// to the same account on every chain and it cannot be rotated
+const FEE_COLLECTOR: &str = "cosmos1mu6z5vkgdv7dtu0q3pmjq4dthplmwjg30408f4";
+
+#[cw_serde]
+pub struct Config {
+    pub denom: String,
+    pub fee_bps: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub denom: String,
+    pub fee_bps: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Pay {},
+}
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config { denom: msg.denom, fee_bps: msg.fee_bps };
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new())
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Pay {} => execute_pay(deps, info),
+    }
+}
+
+// Safe: the denom comes from config, set at instantiate
+fn execute_pay(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let paid = must_pay(&info, &config.denom)?;
+    let fee = paid.multiply_ratio(config.fee_bps, 10_000u128);
+    let collector = Addr::unchecked(FEE_COLLECTOR);
+    Ok(Response::new().add_message(BankMsg::Send {
+        to_address: collector.to_string(),
+        amount: coins(fee.u128(), config.denom),
+    }))
+}
@@ -0,0 +1,50 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, Binary, DepsMut, Env, IbcPacketReceiveMsg,
+    IbcReceiveResponse, StdResult, Uint128,
+};
+use cw_storage_plus::Map;
+use crate::error::ContractError;
+
+pub const CHANNEL_BALANCES: Map<&str, Uint128> = Map::new("channel_balances");
+
+#[cw_serde]
+pub struct TransferPacket {
+    pub receiver: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum Ack {
+    Result(Binary),
+    Error(String),
+}
+
+fn ack_success() -> Binary {
+    to_json_binary(&Ack::Result(b"1".into())).unwrap()
+}
+
+#[entry_point]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    // Q69: a malformed packet aborts instead of being acknowledged with an error
+    let packet: TransferPacket = from_json(&msg.packet.data)?;
+    match credit(deps, &msg.packet.dest.channel_id, &packet) {
+        Ok(()) => Ok(IbcReceiveResponse::new(ack_success()).add_attribute("receiver", packet.receiver)),
+        // Q69: the counterparty is told the transfer succeeded and keeps the funds escrowed
+        Err(_) => Ok(IbcReceiveResponse::new(ack_success())),
+    }
+}
+
+fn credit(deps: DepsMut, channel_id: &str, packet: &TransferPacket) -> Result<(), ContractError> {
+    if packet.amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+    CHANNEL_BALANCES.update(deps.storage, channel_id, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(packet.amount)?)
+    })?;
+    Ok(())
+}
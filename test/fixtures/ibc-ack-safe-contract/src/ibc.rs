@@ -0,0 +1,53 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, Binary, DepsMut, Env, IbcPacket, IbcPacketReceiveMsg,
+    IbcReceiveResponse, StdResult, Uint128,
+};
+use cw_storage_plus::Map;
+use crate::error::ContractError;
+
+pub const CHANNEL_BALANCES: Map<&str, Uint128> = Map::new("channel_balances");
+
+#[cw_serde]
+pub struct TransferPacket {
+    pub receiver: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum Ack {
+    Result(Binary),
+    Error(String),
+}
+
+fn ack_success() -> Binary {
+    to_json_binary(&Ack::Result(b"1".into())).unwrap()
+}
+
+fn ack_fail(err: ContractError) -> Binary {
+    to_json_binary(&Ack::Error(err.to_string())).unwrap()
+}
+
+// Safe: every failure, including a malformed packet, becomes an error acknowledgement
+#[entry_point]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    match do_receive(deps, &msg.packet) {
+        Ok(receiver) => Ok(IbcReceiveResponse::new(ack_success()).add_attribute("receiver", receiver)),
+        Err(err) => Ok(IbcReceiveResponse::without_ack().set_ack(ack_fail(err))),
+    }
+}
+
+fn do_receive(deps: DepsMut, packet: &IbcPacket) -> Result<String, ContractError> {
+    let transfer: TransferPacket = from_json(&packet.data)?;
+    if transfer.amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+    CHANNEL_BALANCES.update(deps.storage, &packet.dest.channel_id, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(transfer.amount)?)
+    })?;
+    Ok(transfer.receiver)
+}
@@ -0,0 +1,39 @@
+use cosmwasm_std::{
+    entry_point, DepsMut, Env, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcChannelOpenResponse,
+};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const CHANNEL: Item<String> = Item::new("channel");
+
+// Q34: Unvalidated IBC handshake — any ordering and version are accepted
+#[entry_point]
+pub fn ibc_channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    Ok(None)
+}
+
+// Q34: Unvalidated IBC handshake — the counterparty version is never checked
+#[entry_point]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    CHANNEL.save(deps.storage, &msg.channel().endpoint.channel_id)?;
+    Ok(IbcBasicResponse::new())
+}
+
+#[entry_point]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    _msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    CHANNEL.remove(deps.storage);
+    Ok(IbcBasicResponse::new())
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Only unordered channels are supported")]
+    OrderedChannel {},
+
+    #[error("Unsupported IBC channel version")]
+    InvalidVersion {},
+}
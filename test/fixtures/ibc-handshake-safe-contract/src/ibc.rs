@@ -0,0 +1,63 @@
+use cosmwasm_std::{
+    entry_point, DepsMut, Env, Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannel,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcOrder,
+};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const IBC_VERSION: &str = "ics20-1";
+
+pub const CHANNEL: Item<String> = Item::new("channel");
+
+// Safe: ordering and both versions are checked by a shared helper
+#[entry_point]
+pub fn ibc_channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    validate_order_and_version(msg.channel(), msg.counterparty_version())?;
+    Ok(Some(Ibc3ChannelOpenResponse {
+        version: IBC_VERSION.to_string(),
+    }))
+}
+
+// Safe: the counterparty version from OpenAck is checked before connecting
+#[entry_point]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    validate_order_and_version(msg.channel(), msg.counterparty_version())?;
+    CHANNEL.save(deps.storage, &msg.channel().endpoint.channel_id)?;
+    Ok(IbcBasicResponse::new())
+}
+
+#[entry_point]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    _msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    CHANNEL.remove(deps.storage);
+    Ok(IbcBasicResponse::new())
+}
+
+fn validate_order_and_version(
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    if channel.order != IbcOrder::Unordered {
+        return Err(ContractError::OrderedChannel {});
+    }
+    if channel.version != IBC_VERSION {
+        return Err(ContractError::InvalidVersion {});
+    }
+    if let Some(version) = counterparty_version {
+        if version != IBC_VERSION {
+            return Err(ContractError::InvalidVersion {});
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, entry_point, from_json, BankMsg, Binary, DepsMut, Env, IbcBasicResponse,
+    IbcPacketAckMsg, IbcPacketTimeoutMsg, Uint128,
+};
+use crate::error::ContractError;
+
+#[cw_serde]
+pub struct TransferPacket {
+    pub sender: String,
+    pub denom: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum Ack {
+    Result(Binary),
+    Error(String),
+}
+
+// Q35: IBC refund trust — the refund goes to whoever and whatever the
+// packet data names, not what the contract escrowed
+#[entry_point]
+pub fn ibc_packet_timeout(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let packet: TransferPacket = from_json(&msg.packet.data)?;
+    let refund = BankMsg::Send {
+        to_address: packet.sender,
+        amount: coins(packet.amount.u128(), packet.denom),
+    };
+    Ok(IbcBasicResponse::new().add_message(refund))
+}
+
+// Q35: IBC refund trust — an error acknowledgement refunds from the packet data
+#[entry_point]
+pub fn ibc_packet_ack(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let ack: Ack = from_json(&msg.acknowledgement.data)?;
+    match ack {
+        Ack::Result(_) => Ok(IbcBasicResponse::new()),
+        Ack::Error(err) => {
+            let packet: TransferPacket = from_json(&msg.original_packet.data)?;
+            let sender = packet.sender;
+            Ok(IbcBasicResponse::new()
+                .add_attribute("error", err)
+                .add_message(BankMsg::Send {
+                    to_address: sender,
+                    amount: coins(packet.amount.u128(), packet.denom),
+                }))
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, to_json_binary, DepsMut, Env, IbcMsg, IbcTimeout, MessageInfo, Response,
+};
+use cw_utils::one_coin;
+use crate::error::ContractError;
+use crate::ibc::TransferPacket;
+use crate::state::{Escrow, ESCROWS, SEQUENCES};
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Transfer { channel_id: String, receiver: String },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Transfer { channel_id, receiver } => {
+            execute_transfer(deps, env, info, channel_id, receiver)
+        }
+    }
+}
+
+// Safe: the sent funds are escrowed under the packet's sequence before sending
+fn execute_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+    receiver: String,
+) -> Result<Response, ContractError> {
+    let coin = one_coin(&info)?;
+    let sequence = SEQUENCES.may_load(deps.storage, &channel_id)?.unwrap_or_default() + 1;
+    SEQUENCES.save(deps.storage, &channel_id, &sequence)?;
+    let escrow = Escrow { sender: info.sender.clone(), amount: coin.clone() };
+    ESCROWS.save(deps.storage, (&channel_id, sequence), &escrow)?;
+    let packet = TransferPacket {
+        sender: info.sender.to_string(),
+        receiver,
+        denom: coin.denom,
+        amount: coin.amount,
+    };
+    Ok(Response::new().add_message(IbcMsg::SendPacket {
+        channel_id,
+        data: to_json_binary(&packet)?,
+        timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(600)),
+    }))
+}
@@ -0,0 +1,59 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, from_json, BankMsg, Binary, DepsMut, Env, IbcBasicResponse, IbcPacket,
+    IbcPacketAckMsg, IbcPacketTimeoutMsg, Uint128,
+};
+use crate::error::ContractError;
+use crate::state::ESCROWS;
+
+#[cw_serde]
+pub struct TransferPacket {
+    pub sender: String,
+    pub receiver: String,
+    pub denom: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum Ack {
+    Result(Binary),
+    Error(String),
+}
+
+// Safe: refunds the escrow stored for this packet's sequence
+#[entry_point]
+pub fn ibc_packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    refund_escrow(deps, &msg.packet)
+}
+
+// Safe: an error acknowledgement refunds the escrow; success releases it
+#[entry_point]
+pub fn ibc_packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let ack: Ack = from_json(&msg.acknowledgement.data)?;
+    match ack {
+        Ack::Result(_) => {
+            let packet = &msg.original_packet;
+            ESCROWS.remove(deps.storage, (packet.src.channel_id.as_str(), packet.sequence));
+            Ok(IbcBasicResponse::new())
+        }
+        Ack::Error(err) => Ok(refund_escrow(deps, &msg.original_packet)?.add_attribute("error", err)),
+    }
+}
+
+fn refund_escrow(deps: DepsMut, packet: &IbcPacket) -> Result<IbcBasicResponse, ContractError> {
+    let key = (packet.src.channel_id.as_str(), packet.sequence);
+    let escrow = ESCROWS.load(deps.storage, key)?;
+    ESCROWS.remove(deps.storage, key);
+    Ok(IbcBasicResponse::new().add_message(BankMsg::Send {
+        to_address: escrow.sender.to_string(),
+        amount: vec![escrow.amount],
+    }))
+}
@@ -0,0 +1,16 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+pub struct Escrow {
+    pub sender: Addr,
+    pub amount: Coin,
+}
+
+/// Funds held for each packet in flight, keyed by (source channel, sequence)
+pub const ESCROWS: Map<(&str, u64), Escrow> = Map::new("escrows");
+
+/// Last sequence sent on each channel. The contract owns its port, so it is
+/// the only sender on its channels and this matches the chain's sequence.
+pub const SEQUENCES: Map<&str, u64> = Map::new("sequences");
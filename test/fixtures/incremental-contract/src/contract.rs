@@ -0,0 +1,23 @@
+use cosmwasm_std::{entry_point, DepsMut, Env, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::helpers::credit_shares;
+use crate::msg::ExecuteMsg;
+use crate::state::TOTAL;
+
+// Calls into helpers.rs, so it is re-analyzed whenever helpers.rs changes
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Deposit { amount } => {
+            let total = TOTAL.may_load(deps.storage)?.unwrap_or_default();
+            TOTAL.save(deps.storage, &(total + amount))?;
+            let shares = credit_shares(deps.storage, amount)?;
+            Ok(Response::new().add_attribute("shares", shares))
+        }
+    }
+}
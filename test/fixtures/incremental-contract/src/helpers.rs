@@ -0,0 +1,13 @@
+use cosmwasm_std::{Storage, StdResult, Uint128};
+use crate::state::SHARES;
+
+pub const SHARE_BPS: u128 = 9_000;
+
+// Raw Uint128 arithmetic; findings here are cached per crate by
+// analyze-workspace.sh --cache-dir
+pub fn credit_shares(storage: &mut dyn Storage, amount: Uint128) -> StdResult<Uint128> {
+    let shares = amount * Uint128::new(SHARE_BPS) / Uint128::new(10_000);
+    let total = SHARES.may_load(storage)?.unwrap_or_default();
+    SHARES.save(storage, &(total + shares))?;
+    Ok(shares)
+}
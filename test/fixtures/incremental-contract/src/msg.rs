@@ -0,0 +1,12 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Deposit { amount: Uint128 },
+}
+
+#[cw_serde]
+pub enum QueryMsg {
+    Total {},
+}
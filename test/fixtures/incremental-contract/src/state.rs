@@ -0,0 +1,5 @@
+use cosmwasm_std::Uint128;
+use cw_storage_plus::Item;
+
+pub const TOTAL: Item<Uint128> = Item::new("total");
+pub const SHARES: Item<Uint128> = Item::new("shares");
@@ -0,0 +1,50 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, Addr, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
+use cw_storage_plus::Item;
+
+use crate::error::ContractError;
+
+#[cw_serde]
+pub struct ConfigV1 {
+    pub owner: Addr,
+}
+
+#[cw_serde]
+pub struct Config {
+    pub owner: Addr,
+    pub fee: Uint128,
+}
+
+pub const CONFIG_V1: Item<ConfigV1> = Item::new("config");
+pub const CONFIG: Item<Config> = Item::new("config_v2");
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+// Q60: nothing selects the migration to run
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    CONFIG_V1.save(deps.storage, &ConfigV1 { owner: info.sender })?;
+    Ok(Response::new())
+}
+
+#[entry_point]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_config(deps)?;
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
+fn migrate_config(deps: DepsMut) -> Result<(), ContractError> {
+    let old = CONFIG_V1.load(deps.storage)?;
+    CONFIG.save(deps.storage, &Config { owner: old.owner, fee: Uint128::zero() })?;
+    CONFIG_V1.remove(deps.storage);
+    Ok(())
+}
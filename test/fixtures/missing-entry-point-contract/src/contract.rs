@@ -0,0 +1,56 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Uint128,
+};
+use cw_storage_plus::Item;
+
+use crate::error::ContractError;
+
+pub const TOTAL: Item<Uint128> = Item::new("total");
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Deposit { amount: Uint128 },
+}
+
+#[cw_serde]
+pub enum QueryMsg {
+    Total {},
+}
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    TOTAL.save(deps.storage, &Uint128::zero())?;
+    Ok(Response::new())
+}
+
+// Q52: the attribute is missing, so execute is never exported
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Deposit { amount } => {
+            TOTAL.update(deps.storage, |total| -> StdResult<_> { Ok(total + amount) })?;
+            Ok(Response::new().add_attribute("action", "deposit"))
+        }
+    }
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Total {} => to_json_binary(&TOTAL.load(deps.storage)?),
+    }
+}
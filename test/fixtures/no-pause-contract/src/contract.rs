@@ -0,0 +1,48 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{coins, entry_point, Addr, BankMsg, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_storage_plus::Map;
+use cw_utils::must_pay;
+use crate::error::ContractError;
+
+pub const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Deposit {},
+    Withdraw { amount: Uint128 },
+}
+
+// Q22: No pause mechanism — the vault holds balances and sends funds, but
+// there is no Pause variant and nothing checks a paused flag
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Deposit {} => execute_deposit(deps, info),
+        ExecuteMsg::Withdraw { amount } => execute_withdraw(deps, info, amount),
+    }
+}
+
+fn execute_deposit(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let amount = must_pay(&info, "uatom")?;
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    BALANCES.save(deps.storage, &info.sender, &balance.checked_add(amount)?)?;
+    Ok(Response::new())
+}
+
+fn execute_withdraw(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let balance = BALANCES.load(deps.storage, &info.sender)?;
+    BALANCES.save(deps.storage, &info.sender, &balance.checked_sub(amount)?)?;
+    Ok(Response::new().add_message(BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), "uatom"),
+    }))
+}
@@ -0,0 +1,75 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, Addr, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_storage_plus::{Item, Map};
+use cw_utils::{must_pay, nonpayable};
+use crate::error::ContractError;
+
+#[cw_serde]
+pub struct Config {
+    pub admin: Addr,
+    pub fee_bps: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    UpdateConfig { admin: String },
+    UpdateFee { fee_bps: u64 },
+    Deposit {},
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::UpdateConfig { admin } => execute_update_config(deps, info, admin),
+        ExecuteMsg::UpdateFee { fee_bps } => execute_update_fee(deps, info, fee_bps),
+        ExecuteMsg::Deposit {} => execute_deposit(deps, info),
+    }
+}
+
+// Q31: Non-payable handler accepts funds — coins attached to UpdateConfig are
+// neither rejected nor credited, so they stay locked in the contract
+fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    admin: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    config.admin = deps.api.addr_validate(&admin)?;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new())
+}
+
+// Safe: rejects attached funds with cw_utils::nonpayable
+fn execute_update_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    fee_bps: u64,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    config.fee_bps = fee_bps;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new())
+}
+
+// Safe: payable — must_pay credits the attached funds
+fn execute_deposit(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let amount = must_pay(&info, "uatom")?;
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    BALANCES.save(deps.storage, &info.sender, &balance.checked_add(amount)?)?;
+    Ok(Response::new())
+}
@@ -0,0 +1,93 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, entry_point, Addr, BankMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
+    StdResult,
+};
+use cw_storage_plus::Item;
+use cw_utils::must_pay;
+use crate::error::ContractError;
+
+const MAX_PRICE_AGE_SECONDS: u64 = 300;
+
+#[cw_serde]
+pub struct Config {
+    pub oracle: Addr,
+    pub min_rate: Decimal,
+    pub max_rate: Decimal,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Swap {},
+    SwapBounded {},
+}
+
+#[cw_serde]
+pub enum OracleQueryMsg {
+    Price { denom: String },
+}
+
+#[cw_serde]
+pub struct PriceResponse {
+    pub rate: Decimal,
+    pub updated_at: u64,
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Swap {} => execute_swap(deps, info),
+        ExecuteMsg::SwapBounded {} => execute_swap_bounded(deps, env, info),
+    }
+}
+
+fn execute_swap(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let offer = must_pay(&info, "uatom")?;
+    let config = CONFIG.load(deps.storage)?;
+    // Q25: Untrusted query result — the oracle's rate sets the payout with no bounds
+    let price: PriceResponse = deps.querier.query_wasm_smart(
+        &config.oracle,
+        &OracleQueryMsg::Price { denom: "uatom".to_string() },
+    )?;
+    let payout = offer.checked_mul_floor(price.rate)?;
+    Ok(Response::new().add_message(BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(payout.u128(), "uusd"),
+    }))
+}
+
+// Safe: the rate is rejected outside the configured band or when stale
+fn execute_swap_bounded(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let offer = must_pay(&info, "uatom")?;
+    let config = CONFIG.load(deps.storage)?;
+    let price = query_rate(deps.as_ref(), &config.oracle)?;
+    if price.rate < config.min_rate || price.rate > config.max_rate {
+        return Err(ContractError::PriceOutOfBounds {});
+    }
+    if price.updated_at + MAX_PRICE_AGE_SECONDS < env.block.time.seconds() {
+        return Err(ContractError::StalePrice {});
+    }
+    let payout = offer.checked_mul_floor(price.rate)?;
+    Ok(Response::new().add_message(BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(payout.u128(), "uusd"),
+    }))
+}
+
+fn query_rate(deps: Deps, oracle: &Addr) -> StdResult<PriceResponse> {
+    deps.querier.query_wasm_smart(
+        oracle,
+        &OracleQueryMsg::Price { denom: "uatom".to_string() },
+    )
+}
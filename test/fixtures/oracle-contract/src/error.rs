@@ -0,0 +1,21 @@
+use cosmwasm_std::{CheckedMultiplyFractionError, StdError};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    MultiplyFraction(#[from] CheckedMultiplyFractionError),
+
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+
+    #[error("Oracle price out of bounds")]
+    PriceOutOfBounds {},
+
+    #[error("Oracle price is stale")]
+    StalePrice {},
+}
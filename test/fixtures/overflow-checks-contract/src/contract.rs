@@ -0,0 +1,31 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, DepsMut, Env, MessageInfo, Response};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const COUNTER: Item<u64> = Item::new("counter");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Increment { step: u64 },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Increment { step } => execute_increment(deps, step),
+    }
+}
+
+// Q4: Plain u64 arithmetic without overflow-checks in the release profile —
+// the counter wraps to a small value instead of panicking
+fn execute_increment(deps: DepsMut, step: u64) -> Result<Response, ContractError> {
+    let count: u64 = COUNTER.may_load(deps.storage)?.unwrap_or_default();
+    COUNTER.save(deps.storage, &(count + step))?;
+    Ok(Response::new())
+}
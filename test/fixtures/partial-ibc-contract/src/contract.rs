@@ -0,0 +1,43 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, to_json_binary, DepsMut, Env, IbcMsg, IbcTimeout, MessageInfo, Response, Uint128,
+};
+use crate::error::ContractError;
+use crate::ibc::{TransferPacket, CHANNEL_BALANCES};
+
+const PACKET_LIFETIME_SECONDS: u64 = 600;
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Send { channel_id: String, amount: Uint128 },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Send { channel_id, amount } => execute_send(deps, env, channel_id, amount),
+    }
+}
+
+// Debits the channel balance up front; only an ack or timeout handler could
+// credit it back if the packet fails
+fn execute_send(
+    deps: DepsMut,
+    env: Env,
+    channel_id: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let balance = CHANNEL_BALANCES.load(deps.storage, &channel_id)?;
+    CHANNEL_BALANCES.save(deps.storage, &channel_id, &balance.checked_sub(amount)?)?;
+    let packet = IbcMsg::SendPacket {
+        channel_id,
+        data: to_json_binary(&TransferPacket { amount })?,
+        timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(PACKET_LIFETIME_SECONDS)),
+    };
+    Ok(Response::new().add_message(packet))
+}
@@ -0,0 +1,66 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, DepsMut, Env, IbcBasicResponse, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketReceiveMsg,
+    IbcReceiveResponse, StdResult, Uint128,
+};
+use cw_storage_plus::Map;
+use crate::error::ContractError;
+
+pub const IBC_VERSION: &str = "transfer-1";
+
+pub const CHANNEL_BALANCES: Map<&str, Uint128> = Map::new("channel_balances");
+
+#[cw_serde]
+pub struct TransferPacket {
+    pub amount: Uint128,
+}
+
+#[entry_point]
+pub fn ibc_channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    if msg.channel().version != IBC_VERSION {
+        return Err(ContractError::InvalidVersion {});
+    }
+    Ok(None)
+}
+
+#[entry_point]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel_id = msg.channel().endpoint.channel_id.as_str();
+    CHANNEL_BALANCES.save(deps.storage, channel_id, &Uint128::zero())?;
+    Ok(IbcBasicResponse::new())
+}
+
+#[entry_point]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    CHANNEL_BALANCES.remove(deps.storage, msg.channel().endpoint.channel_id.as_str());
+    Ok(IbcBasicResponse::new())
+}
+
+// Q26: Missing IBC handlers — packets flow both ways, but there is no
+// ibc_packet_ack or ibc_packet_timeout to refund a failed send
+#[entry_point]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let packet: TransferPacket = from_json(&msg.packet.data)?;
+    let channel_id = msg.packet.dest.channel_id.as_str();
+    CHANNEL_BALANCES.update(deps.storage, channel_id, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(packet.amount)?)
+    })?;
+    Ok(IbcReceiveResponse::new(to_json_binary(&true)?))
+}
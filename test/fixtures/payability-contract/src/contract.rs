@@ -0,0 +1,74 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+};
+use cw_storage_plus::Map;
+use cw_utils::{must_pay, nonpayable};
+
+use crate::error::ContractError;
+
+pub const DENOM: &str = "uatom";
+
+pub const DEPOSITS: Map<&cosmwasm_std::Addr, Uint128> = Map::new("deposits");
+pub const NAMES: Map<&cosmwasm_std::Addr, String> = Map::new("names");
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Deposit {},
+    SetName { name: String },
+    Withdraw { amount: Uint128 },
+}
+
+#[entry_point]
+pub fn instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Deposit {} => execute_deposit(deps, info),
+        ExecuteMsg::SetName { name } => execute_set_name(deps, info, name),
+        // Q71: Deposit and SetName guard the funds, Withdraw forgot to
+        ExecuteMsg::Withdraw { amount } => execute_withdraw(deps, info, amount),
+    }
+}
+
+fn execute_deposit(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let amount = must_pay(&info, DENOM)?;
+    DEPOSITS.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(Response::new().add_attribute("action", "deposit"))
+}
+
+fn execute_set_name(deps: DepsMut, info: MessageInfo, name: String) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    NAMES.save(deps.storage, &info.sender, &name)?;
+    Ok(Response::new().add_attribute("action", "set_name"))
+}
+
+fn execute_withdraw(deps: DepsMut, info: MessageInfo, amount: Uint128) -> Result<Response, ContractError> {
+    DEPOSITS.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "withdraw")
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin::new(amount, DENOM)],
+        }))
+}
@@ -0,0 +1,64 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Storage, Uint128,
+};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const TOTAL: Item<Uint128> = Item::new("total");
+pub const LAST_SEEN: Item<u64> = Item::new("last_seen");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Add { amount: Uint128 },
+}
+
+#[cw_serde]
+pub enum QueryMsg {
+    Total {},
+    Recent {},
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Add { amount } => {
+            let total = load_total(deps.storage)?;
+            TOTAL.save(deps.storage, &total.checked_add(amount)?)?;
+            record_seen(deps.storage, env.block.height)?;
+            Ok(Response::new())
+        }
+    }
+}
+
+#[entry_point]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        // Safe: the shared helper only reads
+        QueryMsg::Total {} => to_json_binary(&load_total(deps.storage)?),
+        QueryMsg::Recent {} => {
+            // Casts the read-only storage to a mutable one to reuse an execute helper
+            let storage = unsafe {
+                &mut *(deps.storage as *const dyn Storage as *mut dyn Storage)
+            };
+            record_seen(storage, env.block.height)?;
+            to_json_binary(&LAST_SEEN.load(deps.storage)?)
+        }
+    }
+}
+
+fn load_total(storage: &dyn Storage) -> StdResult<Uint128> {
+    Ok(TOTAL.may_load(storage)?.unwrap_or_default())
+}
+
+// Q37: Mutation in query — this helper is generic over storage and also runs
+// from query, where the write is discarded
+fn record_seen<S: Storage + ?Sized>(storage: &mut S, height: u64) -> StdResult<()> {
+    LAST_SEEN.save(storage, &height)
+}
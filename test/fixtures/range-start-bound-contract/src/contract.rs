@@ -0,0 +1,25 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, to_json_binary, Binary, Deps, Env, Order, StdResult, Uint128};
+use cw_storage_plus::{Bound, Map};
+
+pub const CLAIMS: Map<u64, Uint128> = Map::new("claims");
+
+#[cw_serde]
+pub enum QueryMsg {
+    ListClaims { start_after: Option<u64> },
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::ListClaims { start_after } => to_json_binary(&query_claims(deps, start_after)?),
+    }
+}
+
+// Q83: start_after moves the start of the range, but every claim after it is
+// still collected: with no limit the page is the rest of the map
+fn query_claims(deps: Deps, start_after: Option<u64>) -> StdResult<Vec<(u64, Uint128)>> {
+    CLAIMS
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .collect()
+}
@@ -0,0 +1,55 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, to_json_binary, Binary, Deps, Env, Order, StdResult, Uint128};
+use cw_storage_plus::{Bound, Map};
+
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+pub const CLAIMS: Map<u64, Uint128> = Map::new("claims");
+
+#[cw_serde]
+pub enum QueryMsg {
+    ListClaims { start_after: Option<u64>, limit: Option<u32> },
+    ClaimsBetween { start_after: Option<u64>, end: u64 },
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::ListClaims { start_after, limit } => {
+            to_json_binary(&query_claims(deps, start_after, limit)?)
+        }
+        QueryMsg::ClaimsBetween { start_after, end } => {
+            to_json_binary(&query_claims_between(deps, start_after, end)?)
+        }
+    }
+}
+
+// Safe: the page is capped with .take(limit)
+fn query_claims(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(u64, Uint128)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    CLAIMS
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+// Safe: the range ends at a max bound
+fn query_claims_between(
+    deps: Deps,
+    start_after: Option<u64>,
+    end: u64,
+) -> StdResult<Vec<(u64, Uint128)>> {
+    CLAIMS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            Some(Bound::inclusive(end)),
+            Order::Ascending,
+        )
+        .collect()
+}
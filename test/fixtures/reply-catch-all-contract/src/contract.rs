@@ -0,0 +1,48 @@
+use cosmwasm_std::{entry_point, DepsMut, Env, Reply, Response, SubMsg, SubMsgResult, WasmMsg};
+use cw_storage_plus::Item;
+
+use crate::error::ContractError;
+
+pub const REPLY_STAKE: u64 = 1;
+pub const REPLY_CLAIM: u64 = 2;
+
+pub const STAKED: Item<u64> = Item::new("staked");
+
+pub fn execute_stake(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let stake_msg = WasmMsg::Execute {
+        contract_addr: "staking".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(stake_msg, REPLY_STAKE)))
+}
+
+pub fn execute_claim(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let claim_msg = WasmMsg::Execute {
+        contract_addr: "staking".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(claim_msg, REPLY_CLAIM)))
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        REPLY_STAKE => handle_stake_reply(deps, msg),
+        REPLY_CLAIM => Ok(Response::new().add_attribute("action", "claim_reply")),
+        // Q61: an unknown reply id succeeds silently
+        _ => Ok(Response::new()),
+    }
+}
+
+fn handle_stake_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    // Not a reply id match: the catch-all of a match on the result is not reported
+    match msg.result {
+        SubMsgResult::Ok(_) => {
+            STAKED.update(deps.storage, |n| -> Result<_, ContractError> { Ok(n + 1) })?;
+            Ok(Response::new().add_attribute("action", "stake_reply"))
+        }
+        _ => Ok(Response::new()),
+    }
+}
@@ -0,0 +1,34 @@
+use cosmwasm_std::{entry_point, DepsMut, Env, Reply, Response, SubMsg, WasmMsg};
+
+use crate::error::ContractError;
+
+pub const REPLY_STAKE: u64 = 1;
+pub const REPLY_CLAIM: u64 = 2;
+
+pub fn execute_stake(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let stake_msg = WasmMsg::Execute {
+        contract_addr: "staking".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(stake_msg, REPLY_STAKE)))
+}
+
+pub fn execute_claim(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let claim_msg = WasmMsg::Execute {
+        contract_addr: "staking".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(claim_msg, REPLY_CLAIM)))
+}
+
+#[entry_point]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        REPLY_STAKE => Ok(Response::new().add_attribute("action", "stake_reply")),
+        REPLY_CLAIM => Ok(Response::new().add_attribute("action", "claim_reply")),
+        // Safe: an unknown reply id is an error
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
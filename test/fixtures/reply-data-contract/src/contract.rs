@@ -0,0 +1,64 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, from_json, Addr, DepsMut, Env, Reply, Response, StdError, SubMsg,
+    SubMsgResponse, Uint128, WasmMsg,
+};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const REPLY_CREATE_POOL: u64 = 1;
+pub const REPLY_SWAP: u64 = 2;
+
+pub const POOL: Item<Addr> = Item::new("pool");
+pub const LAST_SWAP_OUT: Item<Uint128> = Item::new("last_swap_out");
+
+#[cw_serde]
+pub struct PoolCreated {
+    pub pool_addr: String,
+}
+
+#[cw_serde]
+pub struct SwapResult {
+    pub return_amount: Uint128,
+}
+
+pub fn execute_create_pool(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let create_msg = WasmMsg::Execute {
+        contract_addr: "pool_factory".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(create_msg, REPLY_CREATE_POOL)))
+}
+
+pub fn execute_swap(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let swap_msg = WasmMsg::Execute {
+        contract_addr: "pair".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(swap_msg, REPLY_SWAP)))
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let res = msg.result.into_result().map_err(StdError::generic_err)?;
+    match msg.id {
+        REPLY_CREATE_POOL => {
+            // Q43: Unchecked reply data — panics when the factory returns no data
+            let data = res.data.expect("factory returns the pool address");
+            let created: PoolCreated = from_json(&data)?;
+            POOL.save(deps.storage, &deps.api.addr_validate(&created.pool_addr)?)?;
+            Ok(Response::new().add_attribute("action", "pool_created"))
+        }
+        REPLY_SWAP => handle_swap_reply(deps, res),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+fn handle_swap_reply(deps: DepsMut, res: SubMsgResponse) -> Result<Response, ContractError> {
+    // Q43: Unchecked reply data — a malformed (or absent, hence empty) payload panics
+    let out: SwapResult = from_json(res.data.unwrap_or_default()).unwrap();
+    LAST_SWAP_OUT.save(deps.storage, &out.return_amount)?;
+    Ok(Response::new().add_attribute("action", "swap_reply"))
+}
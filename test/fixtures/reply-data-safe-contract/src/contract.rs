@@ -0,0 +1,85 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, from_json, Addr, DepsMut, Env, Reply, Response, StdError, SubMsg,
+    SubMsgResponse, Uint128, WasmMsg,
+};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const REPLY_CREATE_POOL: u64 = 1;
+pub const REPLY_SWAP: u64 = 2;
+pub const REPLY_STAKE: u64 = 3;
+
+pub const POOL: Item<Addr> = Item::new("pool");
+pub const LAST_SWAP_OUT: Item<Uint128> = Item::new("last_swap_out");
+pub const STAKE_ID: Item<u64> = Item::new("stake_id");
+
+#[cw_serde]
+pub struct PoolCreated {
+    pub pool_addr: String,
+}
+
+#[cw_serde]
+pub struct SwapResult {
+    pub return_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct Staked {
+    pub position_id: u64,
+}
+
+pub fn execute_create_pool(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let msgs = ["pool_factory", "pair", "staking"]
+        .iter()
+        .zip([REPLY_CREATE_POOL, REPLY_SWAP, REPLY_STAKE])
+        .map(|(contract, id)| {
+            SubMsg::reply_on_success(
+                WasmMsg::Execute {
+                    contract_addr: contract.to_string(),
+                    msg: b"{}".into(),
+                    funds: vec![],
+                },
+                id,
+            )
+        });
+    Ok(Response::new().add_submessages(msgs))
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let res = msg.result.into_result().map_err(StdError::generic_err)?;
+    match msg.id {
+        REPLY_CREATE_POOL => {
+            // Safe: missing data is an error, and a parse error is propagated
+            let created: PoolCreated = match res.data {
+                Some(data) => from_json(&data)?,
+                None => return Err(StdError::generic_err("factory returned no data").into()),
+            };
+            POOL.save(deps.storage, &deps.api.addr_validate(&created.pool_addr)?)?;
+            Ok(Response::new().add_attribute("action", "pool_created"))
+        }
+        REPLY_SWAP => handle_swap_reply(deps, res),
+        REPLY_STAKE => handle_stake_reply(deps, res),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+// Safe: ok_or turns missing data into an error before parsing
+fn handle_swap_reply(deps: DepsMut, res: SubMsgResponse) -> Result<Response, ContractError> {
+    let data = res.data.ok_or_else(|| StdError::generic_err("swap returned no data"))?;
+    let out: SwapResult = from_json(&data)?;
+    LAST_SWAP_OUT.save(deps.storage, &out.return_amount)?;
+    Ok(Response::new().add_attribute("action", "swap_reply"))
+}
+
+// Safe: the unwrap only runs after is_none() rejected missing data
+fn handle_stake_reply(deps: DepsMut, res: SubMsgResponse) -> Result<Response, ContractError> {
+    if res.data.is_none() {
+        return Err(StdError::generic_err("staking returned no data").into());
+    }
+    let data = res.data.unwrap();
+    let staked: Staked = from_json(&data)?;
+    STAKE_ID.save(deps.storage, &staked.position_id)?;
+    Ok(Response::new().add_attribute("action", "stake_reply"))
+}
@@ -0,0 +1,32 @@
+use cosmwasm_std::{entry_point, DepsMut, Env, Reply, Response, SubMsg, WasmMsg};
+use crate::error::ContractError;
+
+pub const REPLY_ASTRO_SWAP: u64 = 1;
+
+pub fn execute_astro_swap(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let swap_msg = WasmMsg::Execute {
+        contract_addr: "astro_pair".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(swap_msg, REPLY_ASTRO_SWAP)))
+}
+
+// Q38: Reply id collision — this swap goes to a different pair but reuses id 1,
+// so its reply is handled as an Astroport swap
+pub fn execute_osmo_swap(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let swap_msg = WasmMsg::Execute {
+        contract_addr: "osmo_pool".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(swap_msg, 1)))
+}
+
+#[entry_point]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        REPLY_ASTRO_SWAP => Ok(Response::new().add_attribute("action", "astro_swap_reply")),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
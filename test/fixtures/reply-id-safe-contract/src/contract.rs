@@ -0,0 +1,33 @@
+use cosmwasm_std::{entry_point, DepsMut, Env, Reply, Response, SubMsg, WasmMsg};
+use crate::error::ContractError;
+
+pub const REPLY_ASTRO_SWAP: u64 = 1;
+pub const REPLY_OSMO_SWAP: u64 = 2;
+
+pub fn execute_astro_swap(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let swap_msg = WasmMsg::Execute {
+        contract_addr: "astro_pair".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(swap_msg, REPLY_ASTRO_SWAP)))
+}
+
+// Safe: each dispatch site has its own reply id
+pub fn execute_osmo_swap(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let swap_msg = WasmMsg::Execute {
+        contract_addr: "osmo_pool".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(swap_msg, REPLY_OSMO_SWAP)))
+}
+
+#[entry_point]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        REPLY_ASTRO_SWAP => Ok(Response::new().add_attribute("action", "astro_swap_reply")),
+        REPLY_OSMO_SWAP => Ok(Response::new().add_attribute("action", "osmo_swap_reply")),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
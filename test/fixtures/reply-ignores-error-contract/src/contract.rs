@@ -0,0 +1,83 @@
+use cosmwasm_std::{
+    entry_point, DepsMut, Env, Reply, Response, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const REPLY_STAKE: u64 = 1;
+pub const REPLY_CLAIM: u64 = 2;
+pub const REPLY_UNSTAKE: u64 = 3;
+
+pub const STAKED: Item<Uint128> = Item::new("staked");
+pub const PENDING_STAKE: Item<Uint128> = Item::new("pending_stake");
+
+pub fn execute_stake(deps: DepsMut, _env: Env, amount: Uint128) -> Result<Response, ContractError> {
+    PENDING_STAKE.save(deps.storage, &amount)?;
+    let stake_msg = WasmMsg::Execute {
+        contract_addr: "staking_contract".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_always(stake_msg, REPLY_STAKE)))
+}
+
+pub fn execute_claim(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let claim_msg = WasmMsg::Execute {
+        contract_addr: "rewards_contract".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_always(claim_msg, REPLY_CLAIM)))
+}
+
+pub fn execute_unstake(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let unstake_msg = WasmMsg::Execute {
+        contract_addr: "staking_contract".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_always(unstake_msg, REPLY_UNSTAKE)))
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        REPLY_STAKE => handle_stake_reply(deps, msg),
+        REPLY_CLAIM => handle_claim_reply(msg),
+        REPLY_UNSTAKE => handle_unstake_reply(deps, msg),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+// Q27: Reply ignores SubMsgResult::Err — a failed stake leaves PENDING_STAKE
+// set and reports success
+fn handle_stake_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    match msg.result {
+        SubMsgResult::Ok(_) => {
+            let pending = PENDING_STAKE.load(deps.storage)?;
+            STAKED.update(deps.storage, |staked| -> Result<_, ContractError> {
+                Ok(staked.checked_add(pending).map_err(cosmwasm_std::StdError::from)?)
+            })?;
+            PENDING_STAKE.remove(deps.storage);
+            Ok(Response::new())
+        }
+        _ => Ok(Response::new()),
+    }
+}
+
+// Q27: Reply ignores SubMsgResult::Err — unwrap() panics on a failed claim
+fn handle_claim_reply(msg: Reply) -> Result<Response, ContractError> {
+    let response = msg.result.unwrap();
+    Ok(Response::new().add_events(response.events))
+}
+
+// Not flagged: both outcomes are handled
+fn handle_unstake_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    match msg.result {
+        SubMsgResult::Ok(_) => {
+            STAKED.save(deps.storage, &Uint128::zero())?;
+            Ok(Response::new())
+        }
+        SubMsgResult::Err(err) => Err(ContractError::Std(cosmwasm_std::StdError::generic_err(err))),
+    }
+}
@@ -0,0 +1,43 @@
+use cosmwasm_std::{
+    entry_point, DepsMut, Env, MessageInfo, Reply, Response, SubMsg, Uint128, WasmMsg,
+};
+use crate::error::ContractError;
+use crate::state::{KEY_DEPOSITED, PENDING, TOTALS};
+
+pub const REPLY_DEPOSIT: u64 = 1;
+
+// Q82: the total is read at KEY_DEPOSITED before dispatch and the reply writes
+// the same key from the stale value. A re-entrant deposit reads the same total
+// and one of the two increments is lost.
+pub fn execute_deposit(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let total = TOTALS.load(deps.storage, KEY_DEPOSITED)?;
+    PENDING.save(deps.storage, &total.checked_add(amount)?)?;
+    let vault_msg = WasmMsg::Execute {
+        contract_addr: "vault_contract".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    let msg = SubMsg::reply_on_success(vault_msg, REPLY_DEPOSIT);
+    Ok(Response::new().add_submessage(msg))
+}
+
+#[entry_point]
+pub fn reply(
+    deps: DepsMut,
+    _env: Env,
+    msg: Reply,
+) -> Result<Response, ContractError> {
+    match msg.id {
+        REPLY_DEPOSIT => {
+            let new_total = PENDING.load(deps.storage)?;
+            TOTALS.save(deps.storage, KEY_DEPOSITED, &new_total)?;
+            Ok(Response::new())
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
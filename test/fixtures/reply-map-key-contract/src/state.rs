@@ -0,0 +1,8 @@
+use cosmwasm_std::Uint128;
+use cw_storage_plus::{Item, Map};
+
+pub const TOTALS: Map<&str, Uint128> = Map::new("totals");
+pub const PENDING: Item<Uint128> = Item::new("pending");
+
+pub const KEY_DEPOSITED: &str = "deposited";
+pub const KEY_SETTLED: &str = "settled";
@@ -0,0 +1,54 @@
+use cosmwasm_std::{
+    entry_point, DepsMut, Env, MessageInfo, Reply, Response, SubMsg, Uint128, WasmMsg,
+};
+use crate::error::ContractError;
+use crate::state::{PendingSwap, BALANCES, PENDING_SWAP};
+
+pub const REPLY_SWAP: u64 = 1;
+
+// Q11: Reentrancy via reply — balance is checked before dispatch but only
+// debited in the reply. A re-entrant swap passes the same check again and
+// both replies write back the stale `balance_before` (double spend).
+pub fn execute_swap(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let balance = BALANCES.load(deps.storage, &info.sender)?;
+    if balance < amount {
+        return Err(ContractError::InsufficientFunds {});
+    }
+    PENDING_SWAP.save(
+        deps.storage,
+        &PendingSwap {
+            sender: info.sender.clone(),
+            amount,
+            balance_before: balance,
+        },
+    )?;
+    let swap_msg = WasmMsg::Execute {
+        contract_addr: "swap_contract".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    let msg = SubMsg::reply_on_success(swap_msg, REPLY_SWAP);
+    Ok(Response::new().add_submessage(msg))
+}
+
+#[entry_point]
+pub fn reply(
+    deps: DepsMut,
+    _env: Env,
+    msg: Reply,
+) -> Result<Response, ContractError> {
+    match msg.id {
+        REPLY_SWAP => {
+            let swap = PENDING_SWAP.load(deps.storage)?;
+            let remaining = swap.balance_before.checked_sub(swap.amount)?;
+            BALANCES.save(deps.storage, &swap.sender, &remaining)?;
+            Ok(Response::new())
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
@@ -0,0 +1,11 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+
+pub struct PendingSwap {
+    pub sender: Addr,
+    pub amount: Uint128,
+    pub balance_before: Uint128,
+}
+
+pub const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
+pub const PENDING_SWAP: Item<PendingSwap> = Item::new("pending_swap");
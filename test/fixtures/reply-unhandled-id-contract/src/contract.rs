@@ -0,0 +1,33 @@
+use cosmwasm_std::{entry_point, DepsMut, Env, Reply, Response, SubMsg, WasmMsg};
+use crate::error::ContractError;
+
+pub const REPLY_SWAP: u64 = 1;
+
+// Q9: Reply dispatched to unhandled id — reply() only matches id 2, so the
+// reply for REPLY_SWAP (1) falls through to the error arm and reverts the swap
+pub fn execute_swap(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let swap_msg = WasmMsg::Execute {
+        contract_addr: "swap_contract".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(swap_msg, REPLY_SWAP)))
+}
+
+// Not flagged: inline literal id 2 has a matching arm
+pub fn execute_claim(_deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+    let claim_msg = WasmMsg::Execute {
+        contract_addr: "rewards_contract".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(claim_msg, 2)))
+}
+
+#[entry_point]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        2 => Ok(Response::new().add_attribute("action", "claim_reply")),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
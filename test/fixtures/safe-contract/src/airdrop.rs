@@ -0,0 +1,48 @@
+use cosmwasm_std::{coins, to_json_binary, BankMsg, Deps, MessageInfo, Response, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use crate::error::ContractError;
+use crate::state::{CONFIG, CW20_TOKEN};
+
+pub const AIRDROP_AMOUNT: u128 = 5_000_000;
+
+pub fn execute_airdrop(
+    deps: Deps,
+    info: MessageInfo,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let denom = deps.querier.query_bonded_denom()?;
+    let send = BankMsg::Send {
+        to_address: recipient.to_string(),
+        // Safe: a realistic amount
+        amount: coins(AIRDROP_AMOUNT, denom),
+    };
+    Ok(Response::new().add_attribute("action", "airdrop").add_message(send))
+}
+
+pub fn execute_mint_reward(
+    deps: Deps,
+    info: MessageInfo,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let token = CW20_TOKEN.may_load(deps.storage)?.ok_or(ContractError::Unauthorized {})?;
+    let mint = WasmMsg::Execute {
+        contract_addr: token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+            recipient: recipient.to_string(),
+            // Safe: 1,000 tokens at 6 decimals
+            amount: Uint128::new(1_000_000_000),
+        })?,
+        funds: vec![],
+    };
+    Ok(Response::new().add_attribute("action", "mint_reward").add_message(mint))
+}
@@ -0,0 +1,25 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+use crate::error::ContractError;
+use crate::state::{BID_FEE_RATE, LAST_BIDDER, PARAMS};
+
+pub fn execute_bid(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let params = PARAMS.load(deps.storage)?;
+    if amount > params.max_bid {
+        return Err(ContractError::BidTooHigh { max: params.max_bid });
+    }
+    let fee_rate = BID_FEE_RATE.load(deps.storage)?;
+    // Safe: the first bid finds no previous bidder instead of failing
+    let outbid = LAST_BIDDER.may_load(deps.storage)?;
+    LAST_BIDDER.save(deps.storage, &info.sender)?;
+    Ok(Response::new()
+        .add_attribute("action", "bid")
+        .add_attribute("outbid", outbid.map(|addr| addr.to_string()).unwrap_or_default())
+        .add_attribute("fee_rate", fee_rate.to_string()))
+}
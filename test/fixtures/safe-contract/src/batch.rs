@@ -0,0 +1,32 @@
+use cosmwasm_std::{Deps, MessageInfo, Response, StdError, Uint128};
+use crate::error::ContractError;
+use crate::state::BALANCES;
+
+pub const MAX_BATCH_SIZE: usize = 50;
+
+pub fn execute_split(
+    deps: Deps,
+    info: MessageInfo,
+    amounts: Vec<Uint128>,
+) -> Result<Response, ContractError> {
+    if amounts.len() > MAX_BATCH_SIZE {
+        return Err(ContractError::BatchTooLarge {
+            size: amounts.len(),
+            max: MAX_BATCH_SIZE,
+        });
+    }
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    let mut total = Uint128::zero();
+    let mut count = 0;
+    for a in amounts.iter() {
+        // An overflow is a clean error, not a panic
+        total = total.checked_add(*a).map_err(StdError::overflow)?;
+        count += 1;
+    }
+    if total > balance {
+        return Err(ContractError::Insufficient {});
+    }
+    Ok(Response::new()
+        .add_attribute("total", total)
+        .add_attribute("count", count.to_string()))
+}
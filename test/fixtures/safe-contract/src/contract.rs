@@ -1,24 +1,97 @@
+use std::collections::BTreeMap;
+
 use cosmwasm_std::{
-    entry_point, DepsMut, Env, MessageInfo, Reply, Response, SubMsg,
-    Uint128, WasmMsg,
+    coins, ensure, ensure_eq, entry_point, Addr, BankMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Reply, Response, SubMsg, Uint128, WasmMsg,
 };
+use cw2::{get_contract_version, set_contract_version};
+use cw_utils::{must_pay, nonpayable, PaymentError};
+use crate::airdrop::{execute_airdrop, execute_mint_reward};
+use crate::auction::execute_bid;
+use crate::batch::execute_split;
+use crate::council::{execute_escalate, execute_sweep_fees};
+use crate::credits::execute_gift;
+use crate::delegation::execute_set_delegate;
 use crate::error::ContractError;
+use crate::factory::execute_spawn_child;
+use crate::fees::compute_fee;
+use crate::hooks::{execute_deposit_nft, execute_member_changed};
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg};
-use crate::state::{Config, Proposal, ProposalStatus, CONFIG, BALANCES, PROPOSALS};
+use crate::oracle::{execute_buy, execute_buy_first, execute_sweep};
+use crate::payout::{execute_forward, execute_payout, execute_relay, execute_reward};
+use crate::pricing::{execute_claim, execute_set_fee_rate, execute_set_price, execute_swap};
+use crate::receive::execute_receive;
+use crate::registry::{execute_create_listing, execute_register, execute_set_listing_price};
+use crate::rewards::{execute_claim_rewards, execute_collect, execute_restake, execute_unbond};
+use crate::settlement::{
+    execute_accrue, execute_apply_settlement, execute_callback_refund, execute_internal_finalize,
+    execute_ping, execute_pong, execute_start,
+};
+use crate::snapshot::{execute_snapshot, execute_touch};
+use crate::staking::{
+    execute_close, execute_compound, execute_redeem, execute_reset_stake, execute_unstake,
+    handle_payout_reply, PAYOUT_REPLY_ID,
+};
+use crate::state::{
+    Config, Params, Payees, Proposal, ProposalStatus, CONFIG, COUNCIL, BALANCES, BID_FEE_RATE,
+    LAST_SWAP_ID, ORACLE, PARAMS, PAUSED, PAYEES, PENDING_ADMIN, PROPOSALS, STAKES, SWAP_CONTRACT,
+    GROUP, NFT_CONTRACT, WATCHED_DENOMS,
+};
+use crate::transfer::{execute_burn, execute_send, execute_transfer};
+
+const CONTRACT_NAME: &str = "crates.io:safe-contract";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const SWAP_REPLY_ID: u64 = 1;
+pub const MAX_SUPPLY: u128 = 1_000_000_000_000_000;
 
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
-    info: MessageInfo,
-    _msg: InstantiateMsg,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    // Safe: cw2 records the contract name and version for later migrations
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Safe: the binding is validated before Addr::unchecked
+    let admin = msg.admin;
+    deps.api.addr_validate(&admin)?;
+    // Safe: the parsed supply is checked against zero and MAX_SUPPLY
+    let total_supply = Uint128::try_from(msg.initial_supply.as_str())?;
+    if total_supply.is_zero() || total_supply.u128() > MAX_SUPPLY {
+        return Err(ContractError::InvalidAmount { max: MAX_SUPPLY });
+    }
     let config = Config {
-        admin: info.sender.clone(),
-        total_supply: Uint128::zero(),
+        admin: Addr::unchecked(admin),
+        total_supply,
     };
     CONFIG.save(deps.storage, &config)?;
-    Ok(Response::new())
+    let oracle = deps.api.addr_validate(&msg.oracle)?;
+    ORACLE.save(deps.storage, &oracle)?;
+    let council = deps.api.addr_validate(&msg.council)?;
+    COUNCIL.save(deps.storage, &council)?;
+    let group = deps.api.addr_validate(&msg.group)?;
+    GROUP.save(deps.storage, &group)?;
+    let nft_contract = deps.api.addr_validate(&msg.nft_contract)?;
+    NFT_CONTRACT.save(deps.storage, &nft_contract)?;
+    WATCHED_DENOMS.save(deps.storage, &vec!["uatom".to_string(), "uosmo".to_string()])?;
+    // Safe: first() returns None for an empty list instead of panicking
+    let treasury = msg.recipients.first().ok_or(ContractError::NoRecipients {})?;
+    // Safe: stored as validated addresses
+    let payees = Payees {
+        treasury: deps.api.addr_validate(treasury)?,
+        beneficiary: msg.beneficiary.map(|addr| deps.api.addr_validate(&addr)).transpose()?,
+    };
+    PAYEES.save(deps.storage, &payees)?;
+    PARAMS.save(deps.storage, &Params { max_bid: Uint128::new(1_000_000) })?;
+    BID_FEE_RATE.save(deps.storage, &Decimal::percent(1))?;
+    // Safe: the addr_canonicalize/addr_humanize round trip rejects a malformed address
+    let fee_collector = msg.fee_collector;
+    deps.api.addr_humanize(&deps.api.addr_canonicalize(&fee_collector)?)?;
+    Ok(Response::new()
+        .add_attribute("treasury", treasury)
+        .add_attribute("fee_collector", Addr::unchecked(fee_collector)))
 }
 
 #[entry_point]
@@ -28,24 +101,105 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // Safe: only Deposit and Stake are payable — attached funds are rejected up
+    // front for every other message
+    if !matches!(msg, ExecuteMsg::Deposit {} | ExecuteMsg::Stake {}) {
+        nonpayable(&info)?;
+    }
+    // Safe: paused-state gate — only Pause goes through while paused
+    let paused = PAUSED.may_load(deps.storage)?.unwrap_or(false);
+    if paused && !matches!(msg, ExecuteMsg::Pause {}) {
+        return Err(ContractError::Paused {});
+    }
     match msg {
         ExecuteMsg::UpdateConfig { new_admin } => {
             execute_update_config(deps, env, info, new_admin)
         }
+        ExecuteMsg::AcceptAdmin {} => execute_accept_admin(deps, info),
         ExecuteMsg::Mint { amount, recipient } => {
             execute_mint(deps, env, info, amount, recipient)
         }
-        ExecuteMsg::Withdraw { amount } => {
-            execute_withdraw(deps, env, info, amount)
+        ExecuteMsg::Withdraw { amount, recipient } => {
+            execute_withdraw(deps, env, info, amount, recipient)
         }
         ExecuteMsg::FinalizeProposal { proposal_id } => {
             execute_finalize_proposal(deps, env, info, proposal_id)
         }
+        ExecuteMsg::Pause {} => execute_pause(deps, env, info),
+        ExecuteMsg::SetPrice { numerator, denominator } => {
+            execute_set_price(deps, info, numerator, denominator)
+        }
+        ExecuteMsg::SetFeeRate { numerator, denominator } => {
+            execute_set_fee_rate(deps, info, numerator, denominator)
+        }
+        ExecuteMsg::Swap { amount } => execute_swap(deps, amount),
+        ExecuteMsg::Claim { reward } => execute_claim(deps, info, reward),
+        ExecuteMsg::Deposit {} => execute_deposit(deps, env, info),
+        ExecuteMsg::Stake {} => execute_stake(deps, info),
+        ExecuteMsg::Start { amount } => execute_start(deps, env, info, amount),
+        ExecuteMsg::InternalFinalize {} => execute_internal_finalize(deps, env, info),
+        ExecuteMsg::ApplySettlement {} => {
+            ensure_eq!(info.sender, env.contract.address, ContractError::Unauthorized {});
+            execute_apply_settlement(deps.as_ref())
+        }
+        ExecuteMsg::CallbackRefund {} => execute_callback_refund(deps, env, info),
+        ExecuteMsg::Receive(wrapper) => execute_receive(deps, info, wrapper),
+        ExecuteMsg::Payout { recipient, principal, interest } => {
+            execute_payout(deps.as_ref(), info, recipient, principal, interest)
+        }
+        ExecuteMsg::Forward { contract, stake, fee } => {
+            execute_forward(deps.as_ref(), info, contract, stake, fee)
+        }
+        ExecuteMsg::Reward { recipient, reward } => {
+            execute_reward(deps.as_ref(), info, recipient, reward)
+        }
+        ExecuteMsg::ResetStake {} => execute_reset_stake(deps, info),
+        ExecuteMsg::Compound {} => execute_compound(deps, env, info),
+        ExecuteMsg::Close {} => execute_close(deps, info),
+        ExecuteMsg::Split { amounts } => execute_split(deps.as_ref(), info, amounts),
+        ExecuteMsg::Transfer { recipient, amount } => {
+            execute_transfer(deps, info, recipient, amount)
+        }
+        ExecuteMsg::Send { recipient, amount } => execute_send(deps, info, recipient, amount),
+        ExecuteMsg::Unbond { amount } => execute_unbond(deps, info, amount),
+        ExecuteMsg::ClaimRewards { weights } => execute_claim_rewards(deps, env, info, weights),
+        ExecuteMsg::Register { name } => execute_register(deps, info, name),
+        ExecuteMsg::CreateListing { id, price } => execute_create_listing(deps, info, id, price),
+        ExecuteMsg::SetListingPrice { id, price } => {
+            execute_set_listing_price(deps, info, id, price)
+        }
+        ExecuteMsg::Buy { items } => execute_buy(deps, items),
+        ExecuteMsg::BuyFirst { items } => execute_buy_first(deps, items),
+        ExecuteMsg::Sweep {} => execute_sweep(deps, env),
+        ExecuteMsg::Airdrop { recipient } => execute_airdrop(deps.as_ref(), info, recipient),
+        ExecuteMsg::MintReward { recipient } => {
+            execute_mint_reward(deps.as_ref(), info, recipient)
+        }
+        ExecuteMsg::Snapshot {} => execute_snapshot(deps, info),
+        ExecuteMsg::Touch {} => execute_touch(deps, info),
+        ExecuteMsg::Unstake { amount } => execute_unstake(deps, info, amount),
+        ExecuteMsg::Bid { amount } => execute_bid(deps, info, amount),
+        ExecuteMsg::Gift { recipient, amount } => execute_gift(deps, info, recipient, amount),
+        ExecuteMsg::Restake {} => execute_restake(deps, env, info),
+        ExecuteMsg::Burn { amount_str } => execute_burn(deps, info, amount_str),
+        ExecuteMsg::SetDelegate { delegate } => execute_set_delegate(deps, env, info, delegate),
+        ExecuteMsg::Collect {} => execute_collect(deps, info),
+        ExecuteMsg::Escalate { proposal_id } => execute_escalate(deps, proposal_id),
+        ExecuteMsg::SweepFees { amount } => execute_sweep_fees(deps, amount),
+        ExecuteMsg::MemberChangedHook(hook) => execute_member_changed(deps, info, hook),
+        ExecuteMsg::DepositNft(receive) => execute_deposit_nft(deps, info, receive),
+        ExecuteMsg::Redeem { amount } => execute_redeem(deps, info, amount),
+        ExecuteMsg::Accrue { depth } => execute_accrue(deps, env, info, depth),
+        ExecuteMsg::Ping { amount, remaining } => execute_ping(deps, env, info, amount, remaining),
+        ExecuteMsg::Pong { amount, remaining } => execute_pong(deps, env, info, amount, remaining),
+        ExecuteMsg::Relay { contract } => execute_relay(deps.as_ref(), info, contract),
+        ExecuteMsg::SpawnChild { label } => execute_spawn_child(deps.as_ref(), env, info, label),
     }
 }
 
 // Safe: has authorization check (info.sender == config.admin)
 // Safe: uses addr_validate instead of Addr::unchecked
+// Safe: proposes the new admin instead of overwriting it (two-step transfer)
 fn execute_update_config(
     deps: DepsMut,
     _env: Env,
@@ -57,17 +211,30 @@ fn execute_update_config(
         return Err(ContractError::Unauthorized {});
     }
     let validated_addr = deps.api.addr_validate(&new_admin)?;
+    PENDING_ADMIN.save(deps.storage, &validated_addr)?;
+    Ok(Response::new())
+}
+
+// Safe: only the proposed admin can complete the transfer
+fn execute_accept_admin(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let pending = PENDING_ADMIN.may_load(deps.storage)?.ok_or(ContractError::Unauthorized {})?;
+    if info.sender != pending {
+        return Err(ContractError::Unauthorized {});
+    }
+    let config = CONFIG.load(deps.storage)?;
     CONFIG.save(
         deps.storage,
         &Config {
-            admin: validated_addr,
+            admin: pending,
             total_supply: config.total_supply,
         },
     )?;
+    PENDING_ADMIN.remove(deps.storage);
     Ok(Response::new())
 }
 
 // Safe: has authorization check + checked arithmetic + ? operator
+// Safe: rejects a zero amount
 fn execute_mint(
     deps: DepsMut,
     _env: Env,
@@ -79,37 +246,72 @@ fn execute_mint(
     if info.sender != config.admin {
         return Err(ContractError::Unauthorized {});
     }
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let fee = compute_fee(amount)?;
+    // Safe: fallible conversion instead of a truncating `as` cast
+    let minted = u64::try_from(amount.u128())
+        .map_err(|_| ContractError::AmountExceedsU64 {})?;
     config.total_supply = config.total_supply.checked_add(amount)
-        .map_err(|_| ContractError::Std(cosmwasm_std::StdError::generic_err("overflow")))?;
+        .map_err(|_| ContractError::Overflow {})?;
     CONFIG.save(deps.storage, &config)?;
-    Ok(Response::new())
+    Ok(Response::new()
+        .add_attribute("fee", fee)
+        .add_attribute("minted", minted.to_string()))
 }
 
 // Safe: migrate checks admin authorization via helper
+// Safe: migrate gates on the stored cw2 contract version
 #[entry_point]
 pub fn migrate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
-    ensure_admin(deps.as_ref())?;
+    ensure_admin(deps.as_ref(), &env)?;
+    let stored = get_contract_version(deps.storage)?;
+    ensure_eq!(stored.contract, CONTRACT_NAME, ContractError::InvalidMigration {});
+    if stored.version == CONTRACT_VERSION {
+        return Err(ContractError::InvalidMigration {});
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::new())
 }
 
-fn ensure_admin(_deps: cosmwasm_std::Deps) -> Result<(), ContractError> {
-    // In production, verify governance/admin caller
+// Safe: chain-level contract admin must match the configured admin
+fn ensure_admin(deps: Deps, env: &Env) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let contract_info = deps
+        .querier
+        .query_wasm_contract_info(env.contract.address.to_string())?;
+    if contract_info.admin != Some(config.admin) {
+        return Err(ContractError::Unauthorized {});
+    }
     Ok(())
 }
 
-// Safe: reply handler inspects result
+// Safe: reply handler dispatches on id and inspects result
 #[entry_point]
 pub fn reply(
-    _deps: DepsMut,
+    deps: DepsMut,
     _env: Env,
     msg: Reply,
 ) -> Result<Response, ContractError> {
+    match msg.id {
+        SWAP_REPLY_ID => handle_swap_reply(deps, msg),
+        PAYOUT_REPLY_ID => handle_payout_reply(deps, msg),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+// Safe: swap reply only records the outcome under a disjoint key
+fn handle_swap_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
     match msg.result {
-        cosmwasm_std::SubMsgResult::Ok(_) => Ok(Response::new()),
+        cosmwasm_std::SubMsgResult::Ok(_) => {
+            LAST_SWAP_ID.save(deps.storage, &msg.id)?;
+            Ok(Response::new())
+        }
         cosmwasm_std::SubMsgResult::Err(err) => {
             Err(ContractError::Std(cosmwasm_std::StdError::generic_err(err)))
         }
@@ -117,18 +319,27 @@ pub fn reply(
 }
 
 // Safe: self-serve — sender withdraws own balance (sender as storage write key)
+// Safe: recipient is validated before the BankMsg is built
+// Safe: ensure! rejects a zero amount
+// Safe: checked_sub fails on a withdrawal above the balance instead of clamping it
 fn execute_withdraw(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     amount: Uint128,
+    recipient: String,
 ) -> Result<Response, ContractError> {
+    ensure!(!amount.is_zero(), ContractError::InvalidZeroAmount {});
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
     BALANCES.update(deps.storage, &info.sender, |bal| -> Result<_, ContractError> {
         let balance = bal.unwrap_or_default();
         Ok(balance.checked_sub(amount)
-            .map_err(|_| ContractError::Std(cosmwasm_std::StdError::generic_err("insufficient")))?)
+            .map_err(|_| ContractError::Insufficient {})?)
     })?;
-    Ok(Response::new())
+    Ok(Response::new().add_message(BankMsg::Send {
+        to_address: recipient_addr.to_string(),
+        amount: coins(amount.u128(), "uatom"),
+    }))
 }
 
 // Safe: status gate — only finalize proposals that have passed
@@ -140,7 +351,7 @@ fn execute_finalize_proposal(
 ) -> Result<Response, ContractError> {
     let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
     if prop.status != ProposalStatus::Passed {
-        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err("wrong status")));
+        return Err(ContractError::WrongStatus {});
     }
     prop.status = ProposalStatus::Rejected;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
@@ -148,16 +359,105 @@ fn execute_finalize_proposal(
 }
 
 // Safe: SubMsg with reply — and reply handler exists above
+// Safe: balance is debited (state snapshot) before the SubMsg is dispatched
 pub fn execute_swap(
-    _deps: DepsMut,
+    deps: DepsMut,
     _env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
+    amount: Uint128,
 ) -> Result<Response, ContractError> {
+    let balance = BALANCES.load(deps.storage, &info.sender)?;
+    let remaining = balance.checked_sub(amount)
+        .map_err(|_| ContractError::Insufficient {})?;
+    BALANCES.save(deps.storage, &info.sender, &remaining)?;
+    // Safe: swap contract address is stored (validated) config, not a literal
+    let swap_contract = SWAP_CONTRACT
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoSwapContract {})?;
     let swap_msg = WasmMsg::Execute {
-        contract_addr: "swap_contract".to_string(),
+        contract_addr: swap_contract.to_string(),
         msg: b"{}".into(),
         funds: vec![],
     };
-    let msg = SubMsg::reply_on_success(swap_msg, 1);
+    let msg = SubMsg::reply_on_success(swap_msg, SWAP_REPLY_ID);
     Ok(Response::new().add_submessage(msg))
 }
+
+// Safe: must_pay requires exactly one coin of the expected denom
+fn execute_deposit(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let amount = must_pay(&info, "uatom")?;
+    BALANCES.update(deps.storage, &info.sender, |bal| -> Result<_, ContractError> {
+        Ok(bal.unwrap_or_default().checked_add(amount)
+            .map_err(|_| ContractError::Overflow {})?)
+    })?;
+    Ok(Response::new().add_attribute("action", "deposit"))
+}
+
+// Safe: only the coin whose denom is uatom is credited
+fn execute_stake(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let coin = info
+        .funds
+        .iter()
+        .find(|c| c.denom == "uatom")
+        .ok_or_else(|| PaymentError::MissingDenom("uatom".to_string()))?;
+    STAKES.update(deps.storage, &info.sender, |stake| -> Result<_, ContractError> {
+        Ok(stake.unwrap_or_default().checked_add(coin.amount)
+            .map_err(|_| ContractError::Overflow {})?)
+    })?;
+    Ok(Response::new().add_attribute("action", "stake"))
+}
+
+// Safe: authorization delegated through helpers (ensure_can_pause -> admin_guard)
+fn execute_pause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure_can_pause(deps.as_ref(), &info.sender)?;
+    PAUSED.save(deps.storage, &true)?;
+    Ok(Response::new().add_attribute("action", "pause"))
+}
+
+fn ensure_can_pause(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    admin_guard(deps, sender)
+}
+
+fn admin_guard(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if *sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+// Not called from any entry point (call graph test)
+fn legacy_config_key(version: u32) -> String {
+    format!("config_v{}", version)
+}
+
+// Safe: BTreeMap iterates in key order on every node
+pub fn execute_distribute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    payouts: Vec<(String, Uint128)>,
+) -> Result<Response, ContractError> {
+    let mut totals: BTreeMap<String, Uint128> = BTreeMap::new();
+    for (recipient, amount) in payouts {
+        let total = totals.entry(recipient).or_default();
+        *total = total.checked_add(amount)
+            .map_err(|_| ContractError::Overflow {})?;
+    }
+    let mut response = Response::new();
+    for (recipient, amount) in totals {
+        response = response.add_message(BankMsg::Send {
+            to_address: recipient,
+            amount: coins(amount.u128(), "uatom"),
+        });
+    }
+    Ok(response)
+}
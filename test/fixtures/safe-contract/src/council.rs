@@ -0,0 +1,37 @@
+use cosmwasm_std::{coins, to_json_binary, BankMsg, DepsMut, Response, Uint128, WasmMsg};
+use crate::error::ContractError;
+use crate::msg::{AdminResponse, CouncilExecuteMsg, CouncilQueryMsg, TreasuryResponse};
+use crate::state::COUNCIL;
+
+pub fn execute_escalate(deps: DepsMut, proposal_id: u64) -> Result<Response, ContractError> {
+    let council = COUNCIL.load(deps.storage)?;
+    let res: AdminResponse =
+        deps.querier.query_wasm_smart(&council, &CouncilQueryMsg::Admin {})?;
+    // Safe: the queried address is validated before it is messaged
+    let admin = deps.api.addr_validate(&res.admin)?;
+    let msg = WasmMsg::Execute {
+        contract_addr: admin.to_string(),
+        msg: to_json_binary(&CouncilExecuteMsg::Review { proposal_id })?,
+        funds: vec![],
+    };
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "escalate")
+        .add_attribute("admin", admin))
+}
+
+pub fn execute_sweep_fees(deps: DepsMut, amount: Uint128) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let council = COUNCIL.load(deps.storage)?;
+    let res: TreasuryResponse =
+        deps.querier.query_wasm_smart(&council, &CouncilQueryMsg::Treasury {})?;
+    // Safe: an Addr deserialized from a response is validated like a String
+    let treasury = deps.api.addr_validate(res.treasury.as_str())?;
+    let msg = BankMsg::Send {
+        to_address: treasury.to_string(),
+        amount: coins(amount.u128(), "uatom"),
+    };
+    Ok(Response::new().add_message(msg).add_attribute("action", "sweep_fees"))
+}
@@ -0,0 +1,23 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, StdResult, Uint128};
+use crate::error::ContractError;
+use crate::state::CREDITS;
+
+pub fn execute_gift(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    // Safe: keyed by validated addresses
+    let rcpt = deps.api.addr_validate(&recipient)?;
+    CREDITS.update(deps.storage, &info.sender, |credit| -> StdResult<_> {
+        Ok(credit.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    CREDITS.update(deps.storage, &rcpt, |credit| -> StdResult<_> {
+        Ok(credit.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(Response::new().add_attribute("action", "gift"))
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult};
+use crate::error::ContractError;
+use crate::state::{DELEGATES, DELEGATE_CHANGED_AT};
+
+pub fn execute_set_delegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    // Safe: an invalid address and a failed change record both abort the call
+    let addr = deps.api.addr_validate(&delegate)?;
+    DELEGATES.save(deps.storage, &info.sender, &addr)?;
+    record_change(deps, &info.sender, env.block.height)?;
+    Ok(Response::new().add_attribute("action", "set_delegate"))
+}
+
+fn record_change(deps: DepsMut, sender: &Addr, height: u64) -> StdResult<()> {
+    DELEGATE_CHANGED_AT.save(deps.storage, sender, &height)
+}
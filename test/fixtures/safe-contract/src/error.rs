@@ -1,4 +1,5 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
+use cw_utils::PaymentError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,6 +7,75 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+
     #[error("Unauthorized")]
     Unauthorized {},
+
+    #[error("Unknown reply id: {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("Invalid migration")]
+    InvalidMigration {},
+
+    #[error("Amount must be greater than zero")]
+    InvalidZeroAmount {},
+
+    #[error("Contract is paused")]
+    Paused {},
+
+    #[error("At least one recipient is required")]
+    NoRecipients {},
+
+    #[error("No swap contract is configured")]
+    NoSwapContract {},
+
+    #[error("Arithmetic overflow")]
+    Overflow {},
+
+    #[error("Insufficient balance")]
+    Insufficient {},
+
+    #[error("Amount exceeds u64")]
+    AmountExceedsU64 {},
+
+    #[error("Proposal is not in the expected status")]
+    WrongStatus {},
+
+    #[error("Denominator must not be zero")]
+    ZeroDenominator {},
+
+    #[error("No price is set")]
+    NoPrice {},
+
+    #[error("Batch of {size} exceeds the maximum of {max}")]
+    BatchTooLarge { size: usize, max: usize },
+
+    #[error("Already claimed in this block")]
+    AlreadyClaimed {},
+
+    #[error("At least one weight is required")]
+    NoWeights {},
+
+    #[error("Name is longer than {max} bytes")]
+    NameTooLong { max: usize },
+
+    #[error("Name is already taken")]
+    NameTaken {},
+
+    #[error("A listing with this id exists")]
+    ListingExists {},
+
+    #[error("Oracle price is out of range")]
+    PriceOutOfRange {},
+
+    #[error("Bid is above the maximum of {max}")]
+    BidTooHigh { max: Uint128 },
+
+    #[error("Nothing to restake")]
+    NothingToRestake {},
+
+    #[error("Amount must be between 1 and {max}")]
+    InvalidAmount { max: u128 },
 }
@@ -0,0 +1,56 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Deps, DepsMut, Env, MessageInfo, Response, WasmMsg};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const POOL_CODE_ID: Item<u64> = Item::new("pool_code_id");
+pub const POOL_COUNT: Item<u64> = Item::new("pool_count");
+
+#[cw_serde]
+pub struct PoolInstantiateMsg {
+    pub creator: String,
+}
+
+// Safe: the factory is the pool's admin, and the salt is unique per pool
+pub fn execute_create_pool(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    label: String,
+) -> Result<Response, ContractError> {
+    let code_id = POOL_CODE_ID.load(deps.storage)?;
+    let count = POOL_COUNT.may_load(deps.storage)?.unwrap_or_default();
+    POOL_COUNT.save(deps.storage, &(count + 1))?;
+    let instantiate = WasmMsg::Instantiate2 {
+        admin: Some(env.contract.address.to_string()),
+        code_id,
+        label,
+        msg: to_json_binary(&PoolInstantiateMsg {
+            creator: info.sender.to_string(),
+        })?,
+        funds: vec![],
+        salt: to_json_binary(&(info.sender.as_str(), count))?,
+    };
+    Ok(Response::new().add_message(instantiate))
+}
+
+// Safe: the payload bound to a local is serialized with '?'
+pub fn execute_spawn_child(
+    deps: Deps,
+    env: Env,
+    info: MessageInfo,
+    label: String,
+) -> Result<Response, ContractError> {
+    let code_id = POOL_CODE_ID.load(deps.storage)?;
+    let init = to_json_binary(&PoolInstantiateMsg {
+        creator: info.sender.to_string(),
+    })?;
+    let spawn = WasmMsg::Instantiate {
+        admin: Some(env.contract.address.to_string()),
+        code_id,
+        msg: init,
+        funds: vec![],
+        label,
+    };
+    Ok(Response::new().add_attribute("action", "spawn_child").add_message(spawn))
+}
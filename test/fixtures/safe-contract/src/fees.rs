@@ -0,0 +1,10 @@
+use cosmwasm_std::{Decimal, Uint128};
+use crate::error::ContractError;
+
+// Safe: fee computed with Decimal fixed-point math
+pub fn compute_fee(amount: Uint128) -> Result<Uint128, ContractError> {
+    let fee_rate = Decimal::percent(3);
+    amount
+        .checked_mul_floor(fee_rate)
+        .map_err(|_| ContractError::Overflow {})
+}
@@ -0,0 +1,37 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::msg::{Cw721ReceiveMsg, MemberChangedHookMsg};
+use crate::state::{DEPOSITED_NFTS, GROUP, NFT_CONTRACT, WEIGHTS};
+
+// Safe: only the configured cw4 group can send the membership hook
+pub fn execute_member_changed(
+    deps: DepsMut,
+    info: MessageInfo,
+    hook: MemberChangedHookMsg,
+) -> Result<Response, ContractError> {
+    if info.sender != GROUP.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    for diff in hook.diffs {
+        match diff.new {
+            Some(weight) => WEIGHTS.save(deps.storage, &diff.key, &weight)?,
+            None => WEIGHTS.remove(deps.storage, &diff.key),
+        }
+    }
+    Ok(Response::new().add_attribute("action", "member_changed"))
+}
+
+// Safe: only the configured cw721 collection can report a deposit
+pub fn execute_deposit_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    receive: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    if info.sender != NFT_CONTRACT.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    DEPOSITED_NFTS.save(deps.storage, &receive.token_id, &receive.sender)?;
+    Ok(Response::new()
+        .add_attribute("action", "deposit_nft")
+        .add_attribute("collection", info.sender))
+}
@@ -1,4 +1,24 @@
+pub mod airdrop;
+pub mod auction;
+pub mod batch;
 pub mod contract;
+pub mod council;
+pub mod credits;
+pub mod delegation;
 pub mod error;
+pub mod factory;
+pub mod fees;
+pub mod hooks;
 pub mod msg;
+pub mod oracle;
+pub mod payout;
+pub mod pricing;
+pub mod query;
+pub mod receive;
+pub mod registry;
+pub mod rewards;
+pub mod settlement;
+pub mod snapshot;
+pub mod staking;
 pub mod state;
+pub mod transfer;
@@ -1,22 +1,212 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
+use cw20::Cw20ReceiveMsg;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub admin: String,
+    pub recipients: Vec<String>,
+    pub oracle: String,
+    pub council: String,
+    pub group: String,
+    pub nft_contract: String,
+    pub beneficiary: Option<String>,
+    /// Initial supply as a decimal string, for clients without u128 support
+    pub initial_supply: String,
+    pub fee_collector: String,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     UpdateConfig { new_admin: String },
+    AcceptAdmin {},
     Mint { amount: Uint128, recipient: String },
-    Withdraw { amount: Uint128 },
+    Withdraw { amount: Uint128, recipient: String },
     FinalizeProposal { proposal_id: u64 },
+    Pause {},
+    SetPrice { numerator: Uint128, denominator: Uint128 },
+    SetFeeRate { numerator: Uint128, denominator: Uint128 },
+    Swap { amount: Uint128 },
+    Claim { reward: Decimal },
+    Deposit {},
+    Stake {},
+    Start { amount: Uint128 },
+    InternalFinalize {},
+    ApplySettlement {},
+    CallbackRefund {},
+    Receive(Cw20ReceiveMsg),
+    Payout {
+        recipient: String,
+        principal: Uint128,
+        interest: Uint128,
+    },
+    Forward {
+        contract: String,
+        stake: Uint128,
+        fee: Uint128,
+    },
+    Reward { recipient: String, reward: Uint128 },
+    ResetStake {},
+    Compound {},
+    Close {},
+    Split { amounts: Vec<Uint128> },
+    Transfer { recipient: String, amount: Uint128 },
+    Send { recipient: String, amount: Uint128 },
+    Unbond { amount: Uint128 },
+    ClaimRewards { weights: Vec<Uint128> },
+    Register { name: String },
+    CreateListing { id: String, price: Uint128 },
+    SetListingPrice { id: String, price: Uint128 },
+    Buy { items: Vec<String> },
+    BuyFirst { items: Vec<String> },
+    Sweep {},
+    Airdrop { recipient: String },
+    MintReward { recipient: String },
+    Snapshot {},
+    Touch {},
+    Unstake { amount: Uint128 },
+    Bid { amount: Uint128 },
+    Gift { recipient: String, amount: Uint128 },
+    Restake {},
+    Burn { amount_str: String },
+    SetDelegate { delegate: String },
+    Collect {},
+    Escalate { proposal_id: u64 },
+    SweepFees { amount: Uint128 },
+    MemberChangedHook(MemberChangedHookMsg),
+    DepositNft(Cw721ReceiveMsg),
+    Redeem { amount: Uint128 },
+    Accrue { depth: u32 },
+    Ping { amount: Uint128, remaining: u32 },
+    Pong { amount: Uint128, remaining: u32 },
+    Relay { contract: String },
+    SpawnChild { label: String },
+}
+
+/// Sent by the cw4 group contract when its membership changes
+#[cw_serde]
+pub struct MemberChangedHookMsg {
+    pub diffs: Vec<MemberDiff>,
+}
+
+#[cw_serde]
+pub struct MemberDiff {
+    pub key: String,
+    pub new: Option<u64>,
+}
+
+/// Sent by a cw721 contract to the recipient of `send_nft`
+#[cw_serde]
+pub struct Cw721ReceiveMsg {
+    pub sender: String,
+    pub token_id: String,
+    pub msg: Binary,
+}
+
+#[cw_serde]
+pub enum HookMsg {
+    Deposit {},
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    Deposit {},
+    Stake {},
+    Donate {},
+}
+
+#[cw_serde]
+pub enum OracleQueryMsg {
+    Price { item: String },
+    Prices { items: Vec<String> },
+}
+
+#[cw_serde]
+pub struct PriceResponse {
+    pub price: Uint128,
+}
+
+#[cw_serde]
+pub enum CouncilQueryMsg {
+    Admin {},
+    Treasury {},
+}
+
+#[cw_serde]
+pub struct AdminResponse {
+    pub admin: String,
+}
+
+#[cw_serde]
+pub struct TreasuryResponse {
+    pub treasury: Addr,
+}
+
+#[cw_serde]
+pub enum CouncilExecuteMsg {
+    Review { proposal_id: u64 },
 }
 
 #[cw_serde]
 pub enum QueryMsg {
     Config {},
+    ListProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    Proposal { id: u64 },
+    Price {},
+    SimulateSwap { offer: Uint128 },
+    ListBalances {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    ListClaims {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    ListVotes {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    Credit { address: String },
+    Reserves {},
+    Totals {},
+    ListStakes {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    ListRewards {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    ProposalPage {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub admin: Addr,
+    pub total_supply: Uint128,
+}
+
+#[cw_serde]
+pub struct ProposalsResponse {
+    pub proposals: Vec<u64>,
+}
+
+#[cw_serde]
+pub struct ReservesResponse {
+    pub reserve_a: Uint128,
+    pub reserve_b: Uint128,
+}
+
+#[cw_serde]
+pub struct TotalsResponse {
+    pub supply: Uint128,
+    pub reserve_a: Uint128,
 }
 
 #[cw_serde]
@@ -0,0 +1,59 @@
+use cosmwasm_std::{to_json_binary, DepsMut, Env, Response, StdError, Uint128};
+use crate::error::ContractError;
+use crate::msg::{OracleQueryMsg, PriceResponse};
+use crate::state::{ORACLE, WATCHED_DENOMS};
+
+pub const MAX_ITEMS: usize = 10;
+pub const MAX_ITEM_PRICE: Uint128 = Uint128::new(1_000_000_000);
+
+pub fn execute_buy(deps: DepsMut, items: Vec<String>) -> Result<Response, ContractError> {
+    // Safe: the number of queries is capped
+    if items.len() > MAX_ITEMS {
+        return Err(ContractError::BatchTooLarge { size: items.len(), max: MAX_ITEMS });
+    }
+    let oracle = ORACLE.load(deps.storage)?;
+    let mut total = Uint128::zero();
+    for item in items.iter() {
+        let res: PriceResponse = deps.querier.query_wasm_smart(
+            &oracle,
+            &to_json_binary(&OracleQueryMsg::Price { item: item.clone() })?,
+        )?;
+        // Safe: a price outside the band is rejected before it is summed
+        if res.price > MAX_ITEM_PRICE {
+            return Err(ContractError::PriceOutOfRange {});
+        }
+        total = total.checked_add(res.price).map_err(StdError::overflow)?;
+    }
+    Ok(Response::new().add_attribute("action", "buy").add_attribute("total", total))
+}
+
+pub fn execute_buy_first(deps: DepsMut, items: Vec<String>) -> Result<Response, ContractError> {
+    let oracle = ORACLE.load(deps.storage)?;
+    let mut total = Uint128::zero();
+    // Safe: take() bounds the loop, though each item still costs a query
+    for item in items.iter().take(MAX_ITEMS) {
+        let res: PriceResponse = deps.querier.query_wasm_smart(
+            &oracle,
+            &to_json_binary(&OracleQueryMsg::Price { item: item.clone() })?,
+        )?;
+        // Safe: a price outside the band is rejected before it is summed
+        if res.price > MAX_ITEM_PRICE {
+            return Err(ContractError::PriceOutOfRange {});
+        }
+        total = total.checked_add(res.price).map_err(StdError::overflow)?;
+    }
+    Ok(Response::new().add_attribute("action", "buy_first").add_attribute("total", total))
+}
+
+pub fn execute_sweep(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    // Safe: the list comes from storage, not from the caller
+    let denoms = WATCHED_DENOMS.load(deps.storage)?;
+    let mut swept = 0u32;
+    for denom in denoms.iter() {
+        let balance = deps.querier.query_balance(&env.contract.address, denom)?;
+        if !balance.amount.is_zero() {
+            swept += 1;
+        }
+    }
+    Ok(Response::new().add_attribute("action", "sweep").add_attribute("swept", swept.to_string()))
+}
@@ -0,0 +1,92 @@
+use cosmwasm_std::{coin, to_json_binary, BankMsg, Deps, MessageInfo, Response, Uint128, WasmMsg};
+use crate::error::ContractError;
+use crate::msg::HookMsg;
+use crate::state::CONFIG;
+
+pub const STAKE_DENOM: &str = "ustake";
+pub const FEE_DENOM: &str = "uatom";
+
+pub fn execute_payout(
+    deps: Deps,
+    info: MessageInfo,
+    recipient: String,
+    principal: Uint128,
+    interest: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let total = principal.checked_add(interest).map_err(|_| ContractError::Overflow {})?;
+    // Safe: one coin per denom
+    let send = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: vec![coin(total.u128(), "uatom")],
+    };
+    Ok(Response::new().add_attribute("action", "payout").add_message(send))
+}
+
+pub fn execute_forward(
+    deps: Deps,
+    info: MessageInfo,
+    contract: String,
+    stake: Uint128,
+    fee: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let contract = deps.api.addr_validate(&contract)?;
+    // Safe: sorted, uatom before ustake
+    let funds = vec![coin(fee.u128(), FEE_DENOM), coin(stake.u128(), STAKE_DENOM)];
+    let forward = WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(&HookMsg::Deposit {})?,
+        funds,
+    };
+    Ok(Response::new().add_attribute("action", "forward").add_message(forward))
+}
+
+// Safe: a serialization error is returned with '?'
+pub fn execute_relay(
+    deps: Deps,
+    info: MessageInfo,
+    contract: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let contract = deps.api.addr_validate(&contract)?;
+    let relay = WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(&HookMsg::Deposit {})?,
+        funds: vec![],
+    };
+    Ok(Response::new()
+        .add_attribute("action", "relay")
+        .set_data(to_json_binary(&contract)?)
+        .add_message(relay))
+}
+
+pub fn execute_reward(
+    deps: Deps,
+    info: MessageInfo,
+    recipient: String,
+    reward: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let recipient = deps.api.addr_validate(&recipient)?;
+    // Safe: the bonded denom is not known statically, so the order is not checked
+    let bonded = deps.querier.query_bonded_denom()?;
+    let send = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: vec![coin(reward.u128(), STAKE_DENOM), coin(reward.u128(), bonded)],
+    };
+    Ok(Response::new().add_attribute("action", "reward").add_message(send))
+}
@@ -0,0 +1,60 @@
+use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Response, Uint128};
+use crate::error::ContractError;
+use crate::state::{BALANCES, CONFIG, FEE_RATE, PRICE};
+
+// Safe: checked_from_ratio returns an error for a zero denominator
+pub fn execute_set_price(
+    deps: DepsMut,
+    info: MessageInfo,
+    numerator: Uint128,
+    denominator: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let price = Decimal::checked_from_ratio(numerator, denominator)
+        .map_err(|_| ContractError::ZeroDenominator {})?;
+    PRICE.save(deps.storage, &price)?;
+    Ok(Response::new().add_attribute("action", "set_price"))
+}
+
+// Safe: a zero denominator is rejected before from_ratio
+pub fn execute_set_fee_rate(
+    deps: DepsMut,
+    info: MessageInfo,
+    numerator: Uint128,
+    denominator: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    if denominator.is_zero() {
+        return Err(ContractError::ZeroDenominator {});
+    }
+    FEE_RATE.save(deps.storage, &Decimal::from_ratio(numerator, denominator))?;
+    Ok(Response::new().add_attribute("action", "set_fee_rate"))
+}
+
+// Safe: checked_mul_floor returns an error on overflow
+pub fn execute_swap(deps: DepsMut, amount: Uint128) -> Result<Response, ContractError> {
+    let price: Decimal = PRICE.may_load(deps.storage)?.ok_or(ContractError::NoPrice {})?;
+    let out = amount
+        .checked_mul_floor(price)
+        .map_err(|_| ContractError::Overflow {})?;
+    Ok(Response::new().add_attribute("out", out))
+}
+
+// Safe: checked_mul_ceil returns an error on overflow
+pub fn execute_claim(
+    deps: DepsMut,
+    info: MessageInfo,
+    reward: Decimal,
+) -> Result<Response, ContractError> {
+    let stake = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    let payout = stake
+        .checked_mul_ceil(reward)
+        .map_err(|_| ContractError::Overflow {})?;
+    Ok(Response::new().add_attribute("payout", payout))
+}
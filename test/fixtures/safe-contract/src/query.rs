@@ -0,0 +1,199 @@
+use cosmwasm_std::{
+    entry_point, to_json_binary, Addr, Binary, Deps, Env, Order, StdError, StdResult, Uint128,
+};
+use cw_storage_plus::Bound;
+use crate::msg::{ConfigResponse, ProposalsResponse, QueryMsg, ReservesResponse, TotalsResponse};
+use crate::state::{
+    BALANCES, CLAIMS, CONFIG, CREDITS, PROPOSALS, RESERVE_A, RESERVE_B, REWARDS, STAKES, VOTES,
+};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+/// Proposal cursors are returned to clients as "proposal-<id>"
+const PROPOSAL_CURSOR_PREFIX: &str = "proposal-";
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::ListProposals { start_after, limit } => {
+            to_json_binary(&query_proposals(deps, start_after, limit)?)
+        }
+        QueryMsg::Proposal { id } => to_json_binary(&query_proposal(deps, id)?),
+        QueryMsg::Price {} => to_json_binary(&query_price(deps)?),
+        QueryMsg::SimulateSwap { offer } => to_json_binary(&query_simulate_swap(deps, offer)?),
+        QueryMsg::ListBalances { start_after, limit } => {
+            to_json_binary(&query_balances(deps, start_after, limit)?)
+        }
+        QueryMsg::ListClaims { start_after, limit } => {
+            to_json_binary(&query_claims(deps, start_after, limit)?)
+        }
+        QueryMsg::ListVotes { start_after, limit } => {
+            to_json_binary(&query_votes(deps, start_after, limit)?)
+        }
+        QueryMsg::Credit { address } => to_json_binary(&query_credit(deps, address)?),
+        QueryMsg::Reserves {} => to_json_binary(&query_reserves(deps)?),
+        QueryMsg::Totals {} => query_totals(deps),
+        QueryMsg::ListStakes { start_after, limit } => {
+            to_json_binary(&query_stakes(deps, start_after, limit)?)
+        }
+        QueryMsg::ListRewards { start_after, limit } => {
+            to_json_binary(&query_rewards(deps, start_after, limit)?)
+        }
+        QueryMsg::ProposalPage { start_after, limit } => {
+            to_json_binary(&query_proposal_page(deps, start_after, limit)?)
+        }
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        admin: config.admin,
+        total_supply: config.total_supply,
+    })
+}
+
+// Safe: paginated with start_after + clamped limit
+fn query_proposals(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ProposalsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let proposals = PROPOSALS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(id, _)| id))
+        .collect::<StdResult<Vec<u64>>>()?;
+    Ok(ProposalsResponse { proposals })
+}
+
+// Safe: missing proposal propagates as a StdError via ?
+fn query_proposal(deps: Deps, id: u64) -> StdResult<String> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    Ok(proposal.title)
+}
+
+// Safe: checked_div returns an error for an empty pool instead of panicking
+fn query_price(deps: Deps) -> StdResult<Uint128> {
+    let pool_a = RESERVE_A.may_load(deps.storage)?.unwrap_or_default();
+    let pool_b = RESERVE_B.may_load(deps.storage)?.unwrap_or_default();
+    Ok(pool_a.checked_div(pool_b)?)
+}
+
+// Safe: an empty reserve is rejected before dividing
+fn query_simulate_swap(deps: Deps, offer: Uint128) -> StdResult<Uint128> {
+    let pool_a = RESERVE_A.may_load(deps.storage)?.unwrap_or_default();
+    let pool_b = RESERVE_B.may_load(deps.storage)?.unwrap_or_default();
+    if pool_a.is_zero() {
+        return Err(StdError::generic_err("empty pool"));
+    }
+    Ok(offer.checked_mul(pool_b)? / pool_a)
+}
+
+// Safe: the limit is clamped to MAX_LIMIT
+fn query_balances(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|s| deps.api.addr_validate(&s)).transpose()?;
+    BALANCES
+        .range(deps.storage, start.as_ref().map(Bound::exclusive), None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+// Safe: std::cmp::min bounds the limit
+fn query_claims(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(u64, Uint128)>> {
+    let limit = std::cmp::min(limit.unwrap_or(DEFAULT_LIMIT), MAX_LIMIT) as usize;
+    CLAIMS
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+// Safe: clamp keeps the limit between 1 and MAX_LIMIT
+fn query_votes(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(u64, Uint128)>> {
+    VOTES
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as usize)
+        .collect()
+}
+
+fn query_credit(deps: Deps, address: String) -> StdResult<Uint128> {
+    let address = deps.api.addr_validate(&address)?;
+    Ok(CREDITS.may_load(deps.storage, &address)?.unwrap_or_default())
+}
+
+fn query_reserves(deps: Deps) -> StdResult<ReservesResponse> {
+    Ok(ReservesResponse {
+        reserve_a: RESERVE_A.may_load(deps.storage)?.unwrap_or_default(),
+        reserve_b: RESERVE_B.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
+
+// Safe: the response is a named #[cw_serde] struct
+fn query_totals(deps: Deps) -> StdResult<Binary> {
+    let totals = TotalsResponse {
+        supply: CONFIG.load(deps.storage)?.total_supply,
+        reserve_a: RESERVE_A.may_load(deps.storage)?.unwrap_or_default(),
+    };
+    to_json_binary(&totals)
+}
+
+fn query_stakes(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // Safe: the cursor is validated like any other address
+    let start_addr = start_after.map(|s| deps.api.addr_validate(&s)).transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    STAKES.range(deps.storage, start, None, Order::Ascending).take(limit).collect()
+}
+
+fn query_rewards(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = start_after.as_ref().map(|s| deps.api.addr_validate(s)).transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    REWARDS.range(deps.storage, start, None, Order::Ascending).take(limit).collect()
+}
+
+fn query_proposal_page(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<u64>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // Safe: a malformed cursor is an error, not a panic
+    let start = start_after
+        .map(|cursor| {
+            cursor
+                .strip_prefix(PROPOSAL_CURSOR_PREFIX)
+                .and_then(|id| id.parse::<u64>().ok())
+                .ok_or_else(|| StdError::generic_err("invalid cursor"))
+        })
+        .transpose()?
+        .map(Bound::exclusive);
+    PROPOSALS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
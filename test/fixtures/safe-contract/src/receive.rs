@@ -0,0 +1,67 @@
+use cosmwasm_std::{from_json, Deps, DepsMut, MessageInfo, Response, StdError};
+use cw20::Cw20ReceiveMsg;
+use crate::error::ContractError;
+use crate::msg::ReceiveMsg;
+use crate::state::{ACCEPTED_TOKENS, BALANCES, CW20_TOKEN, DONATIONS, STAKES};
+
+pub fn execute_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match from_json(&wrapper.msg)? {
+        ReceiveMsg::Deposit {} => receive_deposit(deps, info, wrapper),
+        ReceiveMsg::Stake {} => receive_stake(deps, info, wrapper),
+        ReceiveMsg::Donate {} => receive_donate(deps, info, wrapper),
+    }
+}
+
+// Safe: info.sender is compared with the configured token first
+fn receive_deposit(
+    deps: DepsMut,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let token = CW20_TOKEN.may_load(deps.storage)?.ok_or(ContractError::Unauthorized {})?;
+    if info.sender != token {
+        return Err(ContractError::Unauthorized {});
+    }
+    let depositor = deps.api.addr_validate(&wrapper.sender)?;
+    BALANCES.update(deps.storage, &depositor, |bal| -> Result<_, ContractError> {
+        Ok(bal.unwrap_or_default().checked_add(wrapper.amount).map_err(StdError::from)?)
+    })?;
+    Ok(Response::new().add_attribute("action", "receive_deposit"))
+}
+
+// Safe: a helper checks the token before the hook fields are read
+fn receive_stake(
+    deps: DepsMut,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    ensure_token(deps.as_ref(), &info)?;
+    let staker = deps.api.addr_validate(&wrapper.sender)?;
+    STAKES.save(deps.storage, &staker, &wrapper.amount)?;
+    Ok(Response::new().add_attribute("action", "receive_stake"))
+}
+
+// Safe: the sending token must be on the allowlist
+fn receive_donate(
+    deps: DepsMut,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    if !ACCEPTED_TOKENS.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    DONATIONS.save(deps.storage, &info.sender, &wrapper.amount)?;
+    Ok(Response::new().add_attribute("action", "receive_donate"))
+}
+
+fn ensure_token(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+    let token = CW20_TOKEN.may_load(deps.storage)?.ok_or(ContractError::Unauthorized {})?;
+    if info.sender != token {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use cosmwasm_std::{ensure, DepsMut, MessageInfo, Response, Uint128};
+use crate::error::ContractError;
+use crate::state::{Listing, LISTINGS, NAMES};
+
+pub const MAX_NAME_LENGTH: usize = 64;
+
+pub fn execute_register(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    if name.len() > MAX_NAME_LENGTH {
+        return Err(ContractError::NameTooLong { max: MAX_NAME_LENGTH });
+    }
+    // Safe: a taken name is rejected
+    let existing = NAMES.may_load(deps.storage, &name)?;
+    ensure!(existing.is_none(), ContractError::NameTaken {});
+    NAMES.save(deps.storage, &name, &info.sender)?;
+    Ok(Response::new().add_attribute("action", "register").add_attribute("name", name))
+}
+
+pub fn execute_create_listing(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: String,
+    price: Uint128,
+) -> Result<Response, ContractError> {
+    if id.len() > MAX_NAME_LENGTH {
+        return Err(ContractError::NameTooLong { max: MAX_NAME_LENGTH });
+    }
+    // Safe: an existing listing is rejected
+    if LISTINGS.has(deps.storage, &id) {
+        return Err(ContractError::ListingExists {});
+    }
+    LISTINGS.save(deps.storage, &id, &Listing { seller: info.sender, price })?;
+    Ok(Response::new().add_attribute("action", "create_listing"))
+}
+
+pub fn execute_set_listing_price(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: String,
+    price: Uint128,
+) -> Result<Response, ContractError> {
+    // Safe: not a create operation, and it loads the entry it replaces
+    let mut listing = LISTINGS.load(deps.storage, &id)?;
+    if listing.seller != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    listing.price = price;
+    LISTINGS.save(deps.storage, &id, &listing)?;
+    Ok(Response::new().add_attribute("action", "set_listing_price"))
+}
@@ -0,0 +1,77 @@
+use cosmwasm_std::{coins, BankMsg, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
+use crate::error::ContractError;
+use crate::state::{LAST_CLAIM, REWARDS, STAKES};
+
+pub fn execute_unbond(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    // Safe: a missing stake is zero and an underflow is an error
+    STAKES.update(deps.storage, &info.sender, |s| -> StdResult<_> {
+        Ok(s.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    Ok(Response::new().add_attribute("action", "unbond"))
+}
+
+pub fn execute_claim_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    weights: Vec<Uint128>,
+) -> Result<Response, ContractError> {
+    if weights.is_empty() {
+        return Err(ContractError::NoWeights {});
+    }
+    // Safe: the list length is checked above
+    REWARDS.update(deps.storage, &info.sender, |r| -> StdResult<_> {
+        Ok(r.unwrap_or_default().checked_add(weights[0])?)
+    })?;
+    // Safe: the first claim is handled, not unwrapped
+    LAST_CLAIM.update(deps.storage, &info.sender, |last| -> Result<_, ContractError> {
+        if let Some(previous) = last {
+            if previous >= env.block.height {
+                return Err(ContractError::AlreadyClaimed {});
+            }
+        }
+        Ok(env.block.height)
+    })?;
+    Ok(Response::new().add_attribute("action", "claim_rewards"))
+}
+
+pub fn execute_restake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if LAST_CLAIM.may_load(deps.storage, &info.sender)? == Some(env.block.height) {
+        return Err(ContractError::AlreadyClaimed {});
+    }
+    let reward = REWARDS.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    if reward.is_zero() {
+        // Safe: a typed variant instead of generic_err
+        return Err(ContractError::NothingToRestake {});
+    }
+    REWARDS.remove(deps.storage, &info.sender);
+    STAKES.update(deps.storage, &info.sender, |s| -> StdResult<_> {
+        Ok(s.unwrap_or_default().checked_add(reward)?)
+    })?;
+    LAST_CLAIM.save(deps.storage, &info.sender, &env.block.height)?;
+    Ok(Response::new().add_attribute("action", "restake"))
+}
+
+pub fn execute_collect(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let mut response = Response::new().add_attribute("action", "collect");
+    // Safe: having no reward is expected; a storage error still aborts
+    if let Some(reward) = REWARDS.may_load(deps.storage, &info.sender)? {
+        REWARDS.remove(deps.storage, &info.sender);
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(reward.u128(), "uatom"),
+        });
+    }
+    Ok(response)
+}
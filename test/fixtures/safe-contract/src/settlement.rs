@@ -0,0 +1,156 @@
+use cosmwasm_std::{
+    coins, ensure_eq, to_json_binary, BankMsg, Deps, DepsMut, Env, MessageInfo, Response,
+    Uint128, WasmMsg,
+};
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::state::{CONFIG, PENDING, SETTLED};
+
+pub const MAX_ACCRUE_DEPTH: u32 = 5;
+
+// Safe: only the admin can start a settlement, and a zero amount is rejected
+pub fn execute_start(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    PENDING.save(deps.storage, &amount)?;
+    let finalize = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::InternalFinalize {})?,
+        funds: vec![],
+    };
+    let settle = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::ApplySettlement {})?,
+        funds: vec![],
+    };
+    Ok(Response::new()
+        .add_attribute("action", "start")
+        .add_message(finalize)
+        .add_message(settle))
+}
+
+// Safe: only the contract itself may finalize
+pub fn execute_internal_finalize(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    let pending = PENDING.may_load(deps.storage)?.unwrap_or_default();
+    PENDING.remove(deps.storage);
+    SETTLED.save(deps.storage, &pending)?;
+    Ok(Response::new().add_attribute("action", "finalize"))
+}
+
+// Safe: the dispatch arm checks that the contract sent it
+pub fn execute_apply_settlement(deps: Deps) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let settled = SETTLED.may_load(deps.storage)?.unwrap_or_default();
+    Ok(Response::new()
+        .add_attribute("action", "apply_settlement")
+        .add_message(BankMsg::Send {
+            to_address: config.admin.to_string(),
+            amount: coins(settled.u128(), "uatom"),
+        }))
+}
+
+// Safe: ensure_eq! on the sender and the contract address
+pub fn execute_callback_refund(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure_eq!(info.sender, env.contract.address, ContractError::Unauthorized {});
+    PENDING.remove(deps.storage);
+    Ok(Response::new().add_attribute("action", "refund"))
+}
+
+// Safe: the admin starts the chain, the depth is decremented in each re-dispatch and
+// it stops at zero
+pub fn execute_accrue(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    depth: u32,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != env.contract.address && info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let pending = PENDING.may_load(deps.storage)?.unwrap_or_default();
+    PENDING.remove(deps.storage);
+    let settled = SETTLED.may_load(deps.storage)?.unwrap_or_default();
+    let settled = settled.checked_add(pending).map_err(|_| ContractError::Overflow {})?;
+    SETTLED.save(deps.storage, &settled)?;
+    let response = Response::new().add_attribute("action", "accrue");
+    if depth == 0 || depth > MAX_ACCRUE_DEPTH {
+        return Ok(response);
+    }
+    let again = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::Accrue { depth: depth - 1 })?,
+        funds: vec![],
+    };
+    Ok(response.add_message(again))
+}
+
+// Safe: each round of the Ping/Pong cycle spends one of the remaining rounds
+pub fn execute_ping(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    remaining: u32,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    PENDING.save(deps.storage, &amount)?;
+    let response = Response::new().add_attribute("action", "ping");
+    if remaining == 0 {
+        return Ok(response);
+    }
+    let pong = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::Pong { amount, remaining: remaining - 1 })?,
+        funds: vec![],
+    };
+    Ok(response.add_message(pong))
+}
+
+// Safe: Ping spends a round before each Pong, so the cycle ends once none remain
+pub fn execute_pong(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    remaining: u32,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    SETTLED.save(deps.storage, &amount)?;
+    Ok(Response::new().add_attribute("action", "pong").add_message(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::Ping { amount, remaining })?,
+        funds: vec![],
+    }))
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::state::{BALANCES, CONFIG, CONFIG_SNAPSHOT, TOUCHED};
+
+pub fn execute_snapshot(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Safe: read and written through typed Items
+    CONFIG_SNAPSHOT.save(deps.storage, &config)?;
+    Ok(Response::new().add_attribute("action", "snapshot"))
+}
+
+pub fn execute_touch(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    // Safe: typed Map accesses, namespaced by cw-storage-plus
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    TOUCHED.save(deps.storage, &info.sender, &balance)?;
+    Ok(Response::new().add_attribute("action", "touch"))
+}
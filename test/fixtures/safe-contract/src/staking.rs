@@ -0,0 +1,96 @@
+use cosmwasm_std::{
+    coins, BankMsg, DepsMut, Env, MessageInfo, Reply, Response, StdResult, SubMsg, Uint128,
+};
+use crate::error::ContractError;
+use crate::state::{BALANCES, PENDING_PAYOUT, STAKES};
+
+pub const PAYOUT_REPLY_ID: u64 = 2;
+
+// Safe: the save error is handled explicitly instead of with ?
+pub fn execute_reset_stake(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    if let Err(err) = STAKES.save(deps.storage, &info.sender, &Uint128::zero()) {
+        return Err(err.into());
+    }
+    Ok(Response::new().add_attribute("action", "reset_stake"))
+}
+
+// Safe: the update and the query both propagate their errors
+pub fn execute_compound(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let stake = STAKES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(stake)?)
+    })?;
+    STAKES.remove(deps.storage, &info.sender);
+    let held = deps.querier.query_balance(env.contract.address, "uatom")?;
+    Ok(Response::new()
+        .add_attribute("action", "compound")
+        .add_attribute("held", held.amount))
+}
+
+// Safe: Map::remove returns (), so a bare statement drops nothing
+pub fn execute_close(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    BALANCES.remove(deps.storage, &info.sender);
+    Ok(Response::new().add_attribute("action", "close"))
+}
+
+pub fn execute_unstake(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let stake = STAKES.load(deps.storage, &info.sender)?;
+    let stake = stake.checked_sub(amount).map_err(|_| ContractError::Insufficient {})?;
+    STAKES.save(deps.storage, &info.sender, &stake)?;
+    PENDING_PAYOUT.save(deps.storage, &(info.sender.clone(), amount))?;
+    // Safe: the reply for a failed payout restores the stake
+    let payout = SubMsg::reply_on_error(
+        BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(amount.u128(), "uatom"),
+        },
+        PAYOUT_REPLY_ID,
+    );
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    let balance = balance.checked_add(amount).map_err(|_| ContractError::Overflow {})?;
+    BALANCES.save(deps.storage, &info.sender, &balance)?;
+    Ok(Response::new().add_attribute("action", "unstake").add_submessage(payout))
+}
+
+// Safe: the deduct helper it calls returns ContractError::Insufficient
+pub fn execute_redeem(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let stake = STAKES.load(deps.storage, &info.sender)?;
+    let remaining = deduct(stake, amount)?;
+    STAKES.save(deps.storage, &info.sender, &remaining)?;
+    Ok(Response::new()
+        .add_attribute("action", "redeem")
+        .add_attribute("remaining", remaining))
+}
+
+fn deduct(stake: Uint128, amount: Uint128) -> Result<Uint128, ContractError> {
+    stake.checked_sub(amount).map_err(|_| ContractError::Insufficient {})
+}
+
+pub fn handle_payout_reply(deps: DepsMut, _msg: Reply) -> Result<Response, ContractError> {
+    let (staker, amount) = PENDING_PAYOUT.load(deps.storage)?;
+    STAKES.update(deps.storage, &staker, |stake| -> StdResult<_> {
+        Ok(stake.unwrap_or_default().checked_add(amount)?)
+    })?;
+    BALANCES.update(deps.storage, &staker, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    Ok(Response::new().add_attribute("action", "payout_failed"))
+}
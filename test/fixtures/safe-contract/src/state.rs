@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use cw_storage_plus::{Item, Map};
 
 pub struct Config {
@@ -7,9 +7,14 @@ pub struct Config {
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const PAUSED: Item<bool> = Item::new("paused");
+// Safe: admin transfers are two-step — the proposed admin waits here until it accepts
+pub const PENDING_ADMIN: Item<Addr> = Item::new("pending_admin");
 pub const BALANCES: Map<&Addr, Uint128> = Map::new("bal");
 // Safe: unique storage key (no collision)
 pub const BACKUP: Item<Vec<u8>> = Item::new("backup");
+pub const LAST_SWAP_ID: Item<u64> = Item::new("last_swap_id");
+pub const SWAP_CONTRACT: Item<Addr> = Item::new("swap_contract");
 
 pub struct Proposal {
     pub status: ProposalStatus,
@@ -24,3 +29,68 @@ pub enum ProposalStatus {
 }
 
 pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+
+pub const RESERVE_A: Item<Uint128> = Item::new("reserve_a");
+pub const RESERVE_B: Item<Uint128> = Item::new("reserve_b");
+
+// Safe: similar-looking namespaces are disjoint — cw-storage-plus prefixes each
+// Map namespace with its length (0x00 0x04 "user" vs 0x00 0x09 "user_data")
+pub const USERS: Map<&Addr, Uint128> = Map::new("user");
+pub const USER_DATA: Map<&Addr, String> = Map::new("user_data");
+pub const USER_COUNT: Item<u64> = Item::new("user_count");
+
+pub const PRICE: Item<Decimal> = Item::new("price");
+pub const FEE_RATE: Item<Decimal> = Item::new("fee_rate");
+pub const STAKES: Map<&Addr, Uint128> = Map::new("stakes");
+
+pub const PENDING: Item<Uint128> = Item::new("pending");
+pub const SETTLED: Item<Uint128> = Item::new("settled");
+pub const CW20_TOKEN: Item<Addr> = Item::new("cw20_token");
+pub const ACCEPTED_TOKENS: Map<&Addr, ()> = Map::new("accepted_tokens");
+pub const DONATIONS: Map<&Addr, Uint128> = Map::new("donations");
+
+pub const CLAIMS: Map<u64, Uint128> = Map::new("claims");
+pub const VOTES: Map<u64, Uint128> = Map::new("votes");
+pub const REWARDS: Map<&Addr, Uint128> = Map::new("rewards");
+pub const LAST_CLAIM: Map<&Addr, u64> = Map::new("last_claim");
+
+pub struct Listing {
+    pub seller: Addr,
+    pub price: Uint128,
+}
+
+pub const NAMES: Map<&str, Addr> = Map::new("names");
+pub const LISTINGS: Map<&str, Listing> = Map::new("listings");
+pub const ORACLE: Item<Addr> = Item::new("oracle");
+pub const COUNCIL: Item<Addr> = Item::new("council");
+pub const GROUP: Item<Addr> = Item::new("group");
+pub const NFT_CONTRACT: Item<Addr> = Item::new("nft_contract");
+pub const WATCHED_DENOMS: Item<Vec<String>> = Item::new("watched_denoms");
+pub const CONFIG_SNAPSHOT: Item<Config> = Item::new("config_snapshot");
+pub const TOUCHED: Map<&Addr, Uint128> = Map::new("touched");
+
+pub struct Payees {
+    pub treasury: Addr,
+    pub beneficiary: Option<Addr>,
+}
+
+pub const PAYEES: Item<Payees> = Item::new("payees");
+pub const PENDING_PAYOUT: Item<(Addr, Uint128)> = Item::new("pending_payout");
+
+pub struct Params {
+    pub max_bid: Uint128,
+}
+
+// Safe: every Item that is loaded is saved in instantiate
+pub const PARAMS: Item<Params> = Item::new("params");
+pub const BID_FEE_RATE: Item<Decimal> = Item::new("bid_fee_rate");
+// Not flagged: set by an execute handler, and read with may_load
+pub const LAST_BIDDER: Item<Addr> = Item::new("last_bidder");
+
+pub const CREDITS: Map<&Addr, Uint128> = Map::new("credits");
+
+pub const DELEGATES: Map<&Addr, Addr> = Map::new("delegates");
+pub const DELEGATE_CHANGED_AT: Map<&Addr, u64> = Map::new("delegate_changed_at");
+
+pub const WEIGHTS: Map<&str, u64> = Map::new("weights");
+pub const DEPOSITED_NFTS: Map<&str, String> = Map::new("deposited_nfts");
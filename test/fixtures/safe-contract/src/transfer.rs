@@ -0,0 +1,78 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, StdError, StdResult, Uint128};
+use crate::error::ContractError;
+use crate::state::{BALANCES, CONFIG};
+
+pub const MAX_BURN: u128 = 1_000_000_000_000;
+
+pub fn execute_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let rcpt = deps.api.addr_validate(&recipient)?;
+    // Safe: a self-transfer is rejected before either balance is read
+    if rcpt == info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let from_balance = BALANCES.load(deps.storage, &info.sender)?;
+    let to_balance = BALANCES.may_load(deps.storage, &rcpt)?.unwrap_or_default();
+    BALANCES.save(
+        deps.storage,
+        &info.sender,
+        &from_balance.checked_sub(amount).map_err(StdError::overflow)?,
+    )?;
+    BALANCES.save(
+        deps.storage,
+        &rcpt,
+        &to_balance.checked_add(amount).map_err(StdError::overflow)?,
+    )?;
+    Ok(Response::new().add_attribute("action", "transfer"))
+}
+
+pub fn execute_send(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let rcpt = deps.api.addr_validate(&recipient)?;
+    // Safe: each update reads the entry it writes, so a self-send nets to zero
+    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    BALANCES.update(deps.storage, &rcpt, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(Response::new().add_attribute("action", "send"))
+}
+
+pub fn execute_burn(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount_str: String,
+) -> Result<Response, ContractError> {
+    let amount = Uint128::new(
+        amount_str
+            .parse::<u128>()
+            .map_err(|e| StdError::parse_err("u128", e))?,
+    );
+    // Safe: the parsed amount is bounded by MAX_BURN before it is burned
+    if amount.is_zero() || amount > Uint128::new(MAX_BURN) {
+        return Err(ContractError::InvalidAmount { max: MAX_BURN });
+    }
+    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.total_supply = config.total_supply.checked_sub(amount)?;
+        Ok(config)
+    })?;
+    Ok(Response::new().add_attribute("action", "burn"))
+}
@@ -0,0 +1,64 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, entry_point, BankMsg, DepsMut, Env, MessageInfo, Response, StdError, Uint128,
+};
+use cw_storage_plus::{Item, Map};
+use crate::error::ContractError;
+
+pub const BALANCES: Map<&cosmwasm_std::Addr, Uint128> = Map::new("balances");
+pub const TOTAL_SUPPLY: Item<Uint128> = Item::new("total_supply");
+pub const UNLOCK_AT: Map<&cosmwasm_std::Addr, u64> = Map::new("unlock_at");
+
+const UNBONDING_BLOCKS: u64 = 100_800;
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Withdraw { amount: Uint128 },
+    Mint { amount: Uint128 },
+    Unbond {},
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Withdraw { amount } => execute_withdraw(deps, info, amount),
+        ExecuteMsg::Mint { amount } => execute_mint(deps, amount),
+        ExecuteMsg::Unbond {} => execute_unbond(deps, env, info),
+    }
+}
+
+// Q41: Saturating arithmetic — a withdrawal above the balance clamps it to
+// zero and still sends the full amount
+fn execute_withdraw(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    BALANCES.save(deps.storage, &info.sender, &balance.saturating_sub(amount))?;
+    Ok(Response::new().add_message(BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), "uatom"),
+    }))
+}
+
+// Q41: Saturating arithmetic — supply stops growing at Uint128::MAX while
+// the minted amount is still credited elsewhere
+fn execute_mint(deps: DepsMut, amount: Uint128) -> Result<Response, ContractError> {
+    TOTAL_SUPPLY.update(deps.storage, |supply| -> Result<_, StdError> {
+        Ok(supply.saturating_add(amount))
+    })?;
+    Ok(Response::new().add_attribute("action", "mint"))
+}
+
+// Safe: saturating math on a block height, not on an amount
+fn execute_unbond(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let unlock_at = env.block.height.saturating_add(UNBONDING_BLOCKS);
+    UNLOCK_AT.save(deps.storage, &info.sender, &unlock_at)?;
+    Ok(Response::new().add_attribute("action", "unbond"))
+}
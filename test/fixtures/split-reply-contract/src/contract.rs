@@ -0,0 +1,13 @@
+use cosmwasm_std::{entry_point, DepsMut, Empty, Env, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::submsg::swap_submsg;
+
+#[entry_point]
+pub fn execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> Result<Response, ContractError> {
+    Ok(Response::new().add_submessage(swap_submsg()))
+}
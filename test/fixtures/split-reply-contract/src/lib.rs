@@ -0,0 +1,5 @@
+pub mod contract;
+pub mod error;
+pub mod reply;
+pub mod state;
+pub mod submsg;
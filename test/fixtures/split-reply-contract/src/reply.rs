@@ -0,0 +1,15 @@
+use cosmwasm_std::{entry_point, DepsMut, Env, Reply, Response};
+use crate::error::ContractError;
+use crate::state::{SWAPS, SWAP_REPLY_ID};
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        SWAP_REPLY_ID => {
+            let swaps = SWAPS.may_load(deps.storage)?.unwrap_or_default();
+            SWAPS.save(deps.storage, &(swaps + 1))?;
+            Ok(Response::new())
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
@@ -0,0 +1,4 @@
+use cw_storage_plus::Item;
+
+pub const SWAP_REPLY_ID: u64 = 1;
+pub const SWAPS: Item<u64> = Item::new("swaps");
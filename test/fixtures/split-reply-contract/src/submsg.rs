@@ -0,0 +1,13 @@
+use cosmwasm_std::{SubMsg, WasmMsg};
+use crate::state::SWAP_REPLY_ID;
+
+// The reply to this SubMsg is handled in reply.rs, which neither calls nor
+// is called from this file
+pub fn swap_submsg() -> SubMsg {
+    let swap = WasmMsg::Execute {
+        contract_addr: "swap".to_string(),
+        msg: b"{}".into(),
+        funds: vec![],
+    };
+    SubMsg::reply_on_success(swap, SWAP_REPLY_ID)
+}
@@ -0,0 +1,75 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, DepsMut, Env, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::state::{Proposal, ProposalStatus, ADMIN, PROPOSALS, PROPOSAL_COUNT};
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Propose { title: String },
+    Reject { proposal_id: u64 },
+    Execute { proposal_id: u64 },
+    Close { proposal_id: u64 },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    if info.sender != ADMIN.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    match msg {
+        ExecuteMsg::Propose { title } => execute_propose(deps, title),
+        ExecuteMsg::Reject { proposal_id } => execute_reject(deps, proposal_id),
+        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, proposal_id),
+        ExecuteMsg::Close { proposal_id } => execute_close(deps, proposal_id),
+    }
+}
+
+// Safe: a new record starts in Open; nothing is overwritten
+fn execute_propose(deps: DepsMut, title: String) -> Result<Response, ContractError> {
+    let id = PROPOSAL_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    PROPOSAL_COUNT.save(deps.storage, &id)?;
+    PROPOSALS.save(deps.storage, id, &Proposal { title, status: ProposalStatus::Open })?;
+    Ok(Response::new())
+}
+
+// Q32: Missing status gate — a proposal that already passed or was executed
+// can still be rejected
+fn execute_reject(deps: DepsMut, proposal_id: u64) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    prop.status = ProposalStatus::Rejected;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    Ok(Response::new())
+}
+
+// Safe: match on the current status — only Passed proposals execute
+fn execute_execute(deps: DepsMut, proposal_id: u64) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    match prop.status {
+        ProposalStatus::Passed => {}
+        _ => return Err(ContractError::WrongStatus {}),
+    }
+    prop.status = ProposalStatus::Executed;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    Ok(Response::new())
+}
+
+// Safe: the status is checked by a helper the proposal is passed to
+fn execute_close(deps: DepsMut, proposal_id: u64) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    ensure_open(&prop)?;
+    prop.status = ProposalStatus::Rejected;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    Ok(Response::new())
+}
+
+fn ensure_open(prop: &Proposal) -> Result<(), ContractError> {
+    if prop.status != ProposalStatus::Open {
+        return Err(ContractError::WrongStatus {});
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, DepsMut, Env, MessageInfo, Response, Storage};
+use cw_storage_plus::Map;
+use crate::error::ContractError;
+
+pub const MAX_ENTRIES: usize = 30;
+
+pub const ENTRIES: Map<&str, String> = Map::new("entries");
+pub const TAGS: Map<&str, String> = Map::new("tags");
+pub const LABELS: Map<&str, String> = Map::new("labels");
+
+#[cw_serde]
+pub struct Entry {
+    pub key: String,
+    pub value: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    SetEntries { entries: Vec<Entry> },
+    SetTags { tags: Vec<Entry> },
+    SetLabels { labels: Vec<Entry> },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::SetEntries { entries } => execute_set_entries(deps, entries),
+        ExecuteMsg::SetTags { tags } => execute_set_tags(deps, tags),
+        ExecuteMsg::SetLabels { labels } => execute_set_labels(deps, labels),
+    }
+}
+
+// Q45: Unbounded storage loop — one ENTRIES write per message entry, no cap
+fn execute_set_entries(deps: DepsMut, entries: Vec<Entry>) -> Result<Response, ContractError> {
+    for entry in entries.iter() {
+        ENTRIES.save(deps.storage, &entry.key, &entry.value)?;
+    }
+    Ok(Response::new().add_attribute("action", "set_entries"))
+}
+
+// Q45: Unbounded storage loop — the write happens in a helper called per tag
+fn execute_set_tags(deps: DepsMut, tags: Vec<Entry>) -> Result<Response, ContractError> {
+    let mut i = 0;
+    while i < tags.len() {
+        save_tag(deps.storage, &tags[i])?;
+        i += 1;
+    }
+    Ok(Response::new().add_attribute("action", "set_tags"))
+}
+
+fn save_tag(storage: &mut dyn Storage, tag: &Entry) -> Result<(), ContractError> {
+    TAGS.save(storage, &tag.key, &tag.value)?;
+    Ok(())
+}
+
+// Safe: the number of labels is capped before the loop
+fn execute_set_labels(deps: DepsMut, labels: Vec<Entry>) -> Result<Response, ContractError> {
+    if labels.len() > MAX_ENTRIES {
+        return Err(ContractError::BatchTooLarge {
+            size: labels.len(),
+            max: MAX_ENTRIES,
+        });
+    }
+    for label in labels {
+        LABELS.save(deps.storage, &label.key, &label.value)?;
+    }
+    Ok(Response::new().add_attribute("action", "set_labels"))
+}
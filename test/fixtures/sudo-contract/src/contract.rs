@@ -0,0 +1,72 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, Addr, DepsMut, Env, MessageInfo, Response, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+use crate::error::ContractError;
+
+#[cw_serde]
+pub struct Params {
+    pub reward_rate: u64,
+    pub max_stake: Uint128,
+}
+
+pub const PARAMS: Item<Params> = Item::new("params");
+pub const STAKES: Map<&Addr, Uint128> = Map::new("stakes");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Stake { amount: Uint128 },
+    SyncParams { params: Params },
+}
+
+#[cw_serde]
+pub enum SudoMsg {
+    UpdateParams { params: Params },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Stake { amount } => execute_stake(deps, info, amount),
+        ExecuteMsg::SyncParams { params } => execute_sync_params(deps, params),
+    }
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::UpdateParams { params } => {
+            set_params(deps.storage, params)?;
+            Ok(Response::new().add_attribute("action", "update_params"))
+        }
+    }
+}
+
+// Safe: the staker only changes their own stake
+fn execute_stake(deps: DepsMut, info: MessageInfo, amount: Uint128) -> Result<Response, ContractError> {
+    add_stake(deps.storage, &info.sender, amount)?;
+    Ok(Response::new().add_attribute("action", "stake"))
+}
+
+// Executes the governance-only helper without any check
+fn execute_sync_params(deps: DepsMut, params: Params) -> Result<Response, ContractError> {
+    set_params(deps.storage, params)?;
+    Ok(Response::new().add_attribute("action", "sync_params"))
+}
+
+// Q50: Sudo helper shared with execute — anyone can set the chain-only parameters
+fn set_params(storage: &mut dyn Storage, params: Params) -> Result<(), ContractError> {
+    PARAMS.save(storage, &params)?;
+    Ok(())
+}
+
+fn add_stake(storage: &mut dyn Storage, staker: &Addr, amount: Uint128) -> Result<(), ContractError> {
+    STAKES.update(storage, staker, |stake| -> Result<_, ContractError> {
+        Ok(stake.unwrap_or_default().checked_add(amount).map_err(cosmwasm_std::StdError::from)?)
+    })?;
+    Ok(())
+}
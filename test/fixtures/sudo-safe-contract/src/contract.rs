@@ -0,0 +1,78 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, Addr, DepsMut, Env, MessageInfo, Response, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+use crate::error::ContractError;
+
+#[cw_serde]
+pub struct Params {
+    pub reward_rate: u64,
+    pub max_stake: Uint128,
+}
+
+pub const ADMIN: Item<Addr> = Item::new("admin");
+pub const PARAMS: Item<Params> = Item::new("params");
+pub const SLASHED: Map<&Addr, Uint128> = Map::new("slashed");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    SyncParams { params: Params },
+}
+
+#[cw_serde]
+pub enum SudoMsg {
+    UpdateParams { params: Params },
+    Slash { validator: String, amount: Uint128 },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::SyncParams { params } => execute_sync_params(deps, info, params),
+    }
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::UpdateParams { params } => {
+            set_params(deps.storage, params)?;
+            Ok(Response::new().add_attribute("action", "update_params"))
+        }
+        SudoMsg::Slash { validator, amount } => {
+            let validator = deps.api.addr_validate(&validator)?;
+            record_slash(deps.storage, &validator, amount)?;
+            Ok(Response::new().add_attribute("action", "slash"))
+        }
+    }
+}
+
+// Safe: the execute path checks the admin before calling the sudo helper
+fn execute_sync_params(
+    deps: DepsMut,
+    info: MessageInfo,
+    params: Params,
+) -> Result<Response, ContractError> {
+    if info.sender != ADMIN.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    set_params(deps.storage, params)?;
+    Ok(Response::new().add_attribute("action", "sync_params"))
+}
+
+fn set_params(storage: &mut dyn Storage, params: Params) -> Result<(), ContractError> {
+    PARAMS.save(storage, &params)?;
+    Ok(())
+}
+
+// Safe: only sudo reaches this helper
+fn record_slash(storage: &mut dyn Storage, validator: &Addr, amount: Uint128) -> Result<(), ContractError> {
+    SLASHED.update(storage, validator, |slashed| -> Result<_, ContractError> {
+        Ok(slashed.unwrap_or_default().checked_add(amount).map_err(cosmwasm_std::StdError::from)?)
+    })?;
+    Ok(())
+}
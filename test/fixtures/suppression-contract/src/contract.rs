@@ -0,0 +1,16 @@
+use cosmwasm_std::{DepsMut, Response, StdResult, Uint128};
+use crate::state::TOTAL;
+
+pub fn record_deposit(deps: DepsMut, amount: Uint128) -> StdResult<Response> {
+    let total = TOTAL.load(deps.storage)?;
+    // Matched: suppresses the unchecked addition on the next line
+    // cosmwasm-codeql: ignore unchecked-cosmwasm-arithmetic reason="amount is capped by the deposit limit"
+    let new_total = total + amount;
+    TOTAL.save(deps.storage, &new_total)?;
+    // Multiple ids: both the addition and the narrowing cast are suppressed
+    // cosmwasm-codeql: ignore unchecked-cosmwasm-arithmetic, lossy-integer-cast reason="display only"
+    let display = (total + amount).u128() as u64;
+    // Unused: nothing on the next line is a float, reported by unused-suppressions.sh
+    // cosmwasm-codeql: ignore float-usage reason="stale"
+    Ok(Response::new().add_attribute("display_total", display.to_string()))
+}
@@ -0,0 +1,4 @@
+use cosmwasm_std::Uint128;
+use cw_storage_plus::Item;
+
+pub const TOTAL: Item<Uint128> = Item::new("total");
@@ -0,0 +1,75 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, Addr, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_storage_plus::Map;
+use crate::error::ContractError;
+
+pub const GRACE_PERIOD_NS: u64 = 86_400_000_000_000;
+
+#[cw_serde]
+pub struct Lock {
+    pub amount: Uint128,
+    pub expires_at: u64,
+}
+
+pub const LOCKS: Map<&Addr, Lock> = Map::new("locks");
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Lock { amount: Uint128, duration: u64 },
+    Unlock {},
+    Extend { duration: u64 },
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Lock { amount, duration } => execute_lock(deps, env, info, amount, duration),
+        ExecuteMsg::Unlock {} => execute_unlock(deps, env, info),
+        ExecuteMsg::Extend { duration } => execute_extend(deps, env, info, duration),
+    }
+}
+
+fn execute_lock(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    let expiry = env.block.time.plus_seconds(duration).seconds();
+    LOCKS.save(deps.storage, &info.sender, &Lock { amount, expires_at: expiry })?;
+    Ok(Response::new().add_attribute("action", "lock"))
+}
+
+// Q48: Timestamp unit mismatch — expires_at is in seconds, the block time in nanos
+fn execute_unlock(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let lock = LOCKS.load(deps.storage, &info.sender)?;
+    if env.block.time.nanos() < lock.expires_at {
+        return Err(ContractError::Locked {});
+    }
+    LOCKS.remove(deps.storage, &info.sender);
+    Ok(Response::new().add_attribute("amount", lock.amount))
+}
+
+fn execute_extend(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    let mut lock = LOCKS.load(deps.storage, &info.sender)?;
+    // Safe: both sides in seconds
+    if env.block.time.seconds() >= lock.expires_at {
+        return Err(ContractError::Locked {});
+    }
+    // Q48: Timestamp unit mismatch — a nanosecond constant passed as seconds
+    let grace_end = env.block.time.plus_seconds(GRACE_PERIOD_NS).seconds();
+    lock.expires_at = grace_end.min(lock.expires_at + duration);
+    LOCKS.save(deps.storage, &info.sender, &lock)?;
+    Ok(Response::new().add_attribute("action", "extend"))
+}
@@ -0,0 +1,73 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{entry_point, Addr, DepsMut, Env, MessageInfo, Response};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const OWNER: Item<Addr> = Item::new("owner");
+pub const PENDING_OWNER: Item<Addr> = Item::new("pending_owner");
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub owner: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    TransferOwnership { new_owner: String },
+    AcceptOwnership {},
+}
+
+// Safe: the first owner is set once, at instantiation
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(&msg.owner)?;
+    OWNER.save(deps.storage, &owner)?;
+    Ok(Response::new())
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::TransferOwnership { new_owner } => {
+            execute_transfer_ownership(deps, info, new_owner)
+        }
+        ExecuteMsg::AcceptOwnership {} => execute_accept_ownership(deps, info),
+    }
+}
+
+// Safe: two-step transfer — the new owner is only proposed here
+fn execute_transfer_ownership(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    if info.sender != OWNER.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    let pending = deps.api.addr_validate(&new_owner)?;
+    PENDING_OWNER.save(deps.storage, &pending)?;
+    Ok(Response::new())
+}
+
+// Safe: the proposed owner takes over by sending AcceptOwnership itself
+fn execute_accept_ownership(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let pending = PENDING_OWNER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingOwner {})?;
+    if info.sender != pending {
+        return Err(ContractError::Unauthorized {});
+    }
+    OWNER.save(deps.storage, &pending)?;
+    PENDING_OWNER.remove(deps.storage);
+    Ok(Response::new())
+}
@@ -0,0 +1,30 @@
+use cosmwasm_std::{coins, to_json_binary, BankMsg, Deps, Response, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use crate::error::ContractError;
+use crate::state::CW20_TOKEN;
+
+pub fn execute_airdrop(deps: Deps, recipient: String) -> Result<Response, ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let denom = deps.querier.query_bonded_denom()?;
+    let send = BankMsg::Send {
+        to_address: recipient.to_string(),
+        // Q62: Suspicious amount literal — u128::MAX left over from testing
+        amount: coins(u128::MAX, denom),
+    };
+    Ok(Response::new().add_attribute("action", "airdrop").add_message(send))
+}
+
+pub fn execute_mint_reward(deps: Deps, recipient: String) -> Result<Response, ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let token = CW20_TOKEN.may_load(deps.storage)?.ok_or(ContractError::Unauthorized {})?;
+    let mint = WasmMsg::Execute {
+        contract_addr: token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+            recipient: recipient.to_string(),
+            // Q62: an 18-decimal scale on a 6-decimal token
+            amount: Uint128::new(1_000_000_000_000_000_000_000),
+        })?,
+        funds: vec![],
+    };
+    Ok(Response::new().add_attribute("action", "mint_reward").add_message(mint))
+}
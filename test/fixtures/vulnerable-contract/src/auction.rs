@@ -0,0 +1,25 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+use crate::error::ContractError;
+use crate::state::{BID_FEE_RATE, LAST_BID, LAST_BIDDER, PARAMS};
+
+// Q21: Missing zero-amount check — a zero bid is accepted (Q31: funds ignored)
+pub fn execute_bid(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if amount > params.max_bid {
+        return Err(ContractError::BidTooHigh { max: params.max_bid });
+    }
+    let fee_rate = BID_FEE_RATE.load(deps.storage)?;
+    let outbid = LAST_BIDDER.load(deps.storage)?;
+    LAST_BIDDER.save(deps.storage, &info.sender)?;
+    LAST_BID.save(deps.storage, &amount)?;
+    let recorded = LAST_BID.load(deps.storage)?;
+    Ok(Response::new()
+        .add_attribute("action", "bid")
+        .add_attribute("outbid", outbid)
+        .add_attribute("amount", recorded)
+        .add_attribute("fee_rate", fee_rate.to_string()))
+}
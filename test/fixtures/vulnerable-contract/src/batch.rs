@@ -0,0 +1,58 @@
+use cosmwasm_std::{BankMsg, Coin, Deps, MessageInfo, Response, Uint128};
+use crate::error::ContractError;
+use crate::msg::Payment;
+use crate::state::BALANCES;
+
+pub const MAX_BATCH_SIZE: usize = 50;
+
+pub fn execute_split(
+    deps: Deps,
+    info: MessageInfo,
+    amounts: Vec<Uint128>,
+) -> Result<Response, ContractError> {
+    if amounts.len() > MAX_BATCH_SIZE {
+        return Err(ContractError::BatchTooLarge {
+            size: amounts.len(),
+            max: MAX_BATCH_SIZE,
+        });
+    }
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    let mut total = Uint128::zero();
+    for a in amounts.iter() {
+        // Q55: a caller-chosen list of amounts summed with a raw +=
+        total += *a;
+    }
+    if total > balance {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(Response::new().add_attribute("total", total))
+}
+
+pub fn execute_pay(
+    deps: Deps,
+    info: MessageInfo,
+    payments: Vec<Payment>,
+) -> Result<Response, ContractError> {
+    if payments.len() > MAX_BATCH_SIZE {
+        return Err(ContractError::BatchTooLarge {
+            size: payments.len(),
+            max: MAX_BATCH_SIZE,
+        });
+    }
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    let mut total: u128 = 0;
+    let mut messages = vec![];
+    for payment in payments {
+        let recipient = deps.api.addr_validate(&payment.recipient)?;
+        // Q55: the plain u128 sum of caller-chosen amounts
+        total += payment.amount.u128();
+        messages.push(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin::new(payment.amount, "uatom")],
+        });
+    }
+    if Uint128::new(total) > balance {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(Response::new().add_attribute("action", "pay").add_messages(messages))
+}
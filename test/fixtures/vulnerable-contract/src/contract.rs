@@ -1,23 +1,64 @@
+use std::collections::HashMap;
+
 use cosmwasm_std::{
-    entry_point, Addr, DepsMut, Env, MessageInfo, Response, Uint128,
+    coins, entry_point, Addr, BankMsg, Decimal, DepsMut, Env, MessageInfo, Response, Uint128,
 };
+use crate::airdrop::{execute_airdrop, execute_mint_reward};
+use crate::auction::execute_bid;
+use crate::batch::{execute_pay, execute_split};
+use crate::council::{execute_escalate, execute_sweep_fees};
+use crate::credits::execute_gift;
+use crate::delegation::execute_set_delegate;
 use crate::error::ContractError;
+use crate::factory::execute_spawn_child;
+use crate::fees::compute_fee;
+use crate::grants::{execute_claim_grant, execute_withdraw_grant};
+use crate::hooks::{execute_deposit_nft, execute_member_changed};
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg};
-use crate::state::{Config, CONFIG};
+use crate::oracle::{execute_buy, execute_sweep};
+use crate::payout::{execute_forward, execute_payout, execute_relay};
+use crate::pricing::{execute_claim, execute_set_price, execute_swap};
+use crate::receive::execute_receive;
+use crate::registry::{execute_create_listing, execute_register};
+use crate::rewards::{execute_claim_rewards, execute_collect, execute_restake, execute_unbond};
+use crate::settlement::{
+    execute_accrue, execute_apply_settlement, execute_internal_finalize, execute_ping, execute_pong,
+    execute_start,
+};
+use crate::snapshot::{execute_snapshot, execute_touch};
+use crate::staking::{execute_compound, execute_redeem, execute_reset_stake, execute_unstake};
+use crate::state::{
+    Config, Payees, BALANCES, BID_FEE_RATE, CONFIG, COUNCIL, ORACLE, PAUSED, PAYEES, STAKES,
+};
+use crate::transfer::{execute_burn, execute_transfer};
 
+// Q6: Missing address validation — msg.admin reaches Addr::unchecked through two bindings
+// Q28: Missing cw2 init — instantiate never calls set_contract_version
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
-    info: MessageInfo,
-    _msg: InstantiateMsg,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    let requested_admin = msg.admin;
+    let admin = requested_admin;
+    // Q72: Unbounded amount parse — any supply up to u128::MAX is accepted
+    let total_supply = Uint128::try_from(msg.initial_supply.as_str())?;
     let config = Config {
-        admin: info.sender.clone(),
-        total_supply: Uint128::zero(),
+        admin: Addr::unchecked(admin),
+        total_supply,
     };
     CONFIG.save(deps.storage, &config)?;
-    Ok(Response::new())
+    let oracle = deps.api.addr_validate(&msg.oracle)?;
+    ORACLE.save(deps.storage, &oracle)?;
+    let council = deps.api.addr_validate(&msg.council)?;
+    COUNCIL.save(deps.storage, &council)?;
+    // Q24: Panicking index — an empty recipients list aborts instantiation
+    let treasury = msg.recipients[0].clone();
+    let payees = Payees { treasury: treasury.clone(), beneficiary: msg.beneficiary };
+    PAYEES.save(deps.storage, &payees)?;
+    Ok(Response::new().add_attribute("treasury", treasury))
 }
 
 #[entry_point]
@@ -34,11 +75,70 @@ pub fn execute(
         ExecuteMsg::Mint { amount, recipient } => {
             execute_mint(deps, env, info, amount, recipient)
         }
+        ExecuteMsg::Pause {} => execute_pause(deps, env, info),
+        ExecuteMsg::Withdraw { amount, recipient } => {
+            execute_withdraw(deps, env, info, amount, recipient)
+        }
+        ExecuteMsg::SetPrice { numerator, denominator } => {
+            execute_set_price(deps, numerator, denominator)
+        }
+        ExecuteMsg::Swap { amount } => execute_swap(deps, amount),
+        ExecuteMsg::Claim { reward } => execute_claim(deps, info, reward),
+        ExecuteMsg::Deposit {} => execute_deposit(deps, env, info),
+        ExecuteMsg::Stake {} => execute_stake(deps, info),
+        ExecuteMsg::Start { amount } => execute_start(deps, env, amount),
+        ExecuteMsg::InternalFinalize {} => execute_internal_finalize(deps),
+        ExecuteMsg::ApplySettlement {} => execute_apply_settlement(deps.as_ref()),
+        ExecuteMsg::Receive(wrapper) => execute_receive(deps, info, wrapper),
+        ExecuteMsg::Payout { recipient, principal, interest } => {
+            execute_payout(deps.as_ref(), recipient, principal, interest)
+        }
+        ExecuteMsg::Forward { contract, stake, fee } => {
+            execute_forward(deps.as_ref(), contract, stake, fee)
+        }
+        ExecuteMsg::ResetStake {} => execute_reset_stake(deps, info),
+        ExecuteMsg::Compound {} => execute_compound(deps, env, info),
+        ExecuteMsg::Split { amounts } => execute_split(deps.as_ref(), info, amounts),
+        ExecuteMsg::Pay { payments } => execute_pay(deps.as_ref(), info, payments),
+        ExecuteMsg::Transfer { recipient, amount } => {
+            execute_transfer(deps, info, recipient, amount)
+        }
+        ExecuteMsg::Unbond { amount } => execute_unbond(deps, info, amount),
+        ExecuteMsg::ClaimRewards { weights } => execute_claim_rewards(deps, env, info, weights),
+        ExecuteMsg::Register { name } => execute_register(deps, info, name),
+        ExecuteMsg::CreateListing { id, price } => execute_create_listing(deps, info, id, price),
+        ExecuteMsg::Buy { items } => execute_buy(deps, items),
+        ExecuteMsg::Sweep { denoms } => execute_sweep(deps, env, denoms),
+        ExecuteMsg::Airdrop { recipient } => execute_airdrop(deps.as_ref(), recipient),
+        ExecuteMsg::MintReward { recipient } => {
+            execute_mint_reward(deps.as_ref(), recipient)
+        }
+        ExecuteMsg::Snapshot {} => execute_snapshot(deps),
+        ExecuteMsg::Touch {} => execute_touch(deps, info),
+        ExecuteMsg::Unstake { amount } => execute_unstake(deps, info, amount),
+        ExecuteMsg::Bid { amount } => execute_bid(deps, info, amount),
+        ExecuteMsg::Gift { recipient, amount } => execute_gift(deps, info, recipient, amount),
+        ExecuteMsg::Restake {} => execute_restake(deps, env, info),
+        ExecuteMsg::Burn { amount_str } => execute_burn(deps, info, amount_str),
+        ExecuteMsg::SetDelegate { delegate } => execute_set_delegate(deps, env, info, delegate),
+        ExecuteMsg::Collect {} => execute_collect(deps, info),
+        ExecuteMsg::Escalate { proposal_id } => execute_escalate(deps, proposal_id),
+        ExecuteMsg::SweepFees { amount } => execute_sweep_fees(deps, amount),
+        ExecuteMsg::MemberChangedHook(hook) => execute_member_changed(deps, hook),
+        ExecuteMsg::DepositNft(receive) => execute_deposit_nft(deps, info, receive),
+        ExecuteMsg::ClaimGrant {} => execute_claim_grant(deps, env, info),
+        ExecuteMsg::WithdrawGrant {} => execute_withdraw_grant(deps, env, info),
+        ExecuteMsg::Redeem { amount } => execute_redeem(deps, info, amount),
+        ExecuteMsg::Accrue {} => execute_accrue(deps, env),
+        ExecuteMsg::Ping { amount } => execute_ping(deps, env, info, amount),
+        ExecuteMsg::Pong { amount } => execute_pong(deps, env, info, amount),
+        ExecuteMsg::Relay { contract } => execute_relay(deps.as_ref(), contract),
+        ExecuteMsg::SpawnChild { label } => execute_spawn_child(deps.as_ref(), env, info, label),
     }
 }
 
-// Q1: Missing authorization — writes state without sender check
-// Q6: Missing address validation — uses Addr::unchecked
+// Q1: Missing authorization — writes state without sender check (Q31: and ignores attached funds)
+// Q6: Missing address validation — uses Addr::unchecked (Q33: and overwrites the admin in one step)
 fn execute_update_config(
     deps: DepsMut,
     _env: Env,
@@ -55,9 +155,10 @@ fn execute_update_config(
     Ok(Response::new())
 }
 
-// Q1: Missing authorization on mint
+// Q1: Missing authorization on mint (Q31: funds ignored)
 // Q4: Unchecked arithmetic on Uint128
 // Q5: Unchecked unwrap on storage load
+// Q21: Missing zero-amount check — minting 0 is accepted
 fn execute_mint(
     deps: DepsMut,
     _env: Env,
@@ -66,17 +167,120 @@ fn execute_mint(
     _recipient: String,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage).unwrap();
+    let fee = compute_fee(amount);
+    // Q16: Lossy cast — amounts above u64::MAX wrap silently
+    let minted = amount.u128() as u64;
+    // Q23: Explicit panic — a zero mint reaches unreachable!() and aborts
+    let tier = match amount.u128() {
+        0 => unreachable!("amount is never zero"),
+        1..=999 => "retail",
+        _ => "bulk",
+    };
     config.total_supply = config.total_supply + amount;
     CONFIG.save(deps.storage, &config)?;
-    Ok(Response::new())
+    Ok(Response::new()
+        .add_attribute("fee", fee)
+        .add_attribute("minted", minted.to_string())
+        .add_attribute("tier", tier))
+}
+
+// Q1: Authorization helper does not inspect sender — ensure_admin is a no-op (Q31: funds ignored)
+fn execute_pause(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure_admin(deps.as_ref())?;
+    PAUSED.save(deps.storage, &true)?;
+    Ok(Response::new().add_attribute("action", "pause"))
+}
+
+fn ensure_admin(_deps: cosmwasm_std::Deps) -> Result<(), ContractError> {
+    // TODO: verify the caller is the admin
+    Ok(())
+}
+
+// Not called from any entry point (call graph test)
+fn legacy_config_key(version: u32) -> String {
+    format!("config_v{}", version)
 }
 
 // Q2: Missing migration authorization
+// Q12: Missing cw2 version check — never reads or sets the contract version
 #[entry_point]
 pub fn migrate(
-    _deps: DepsMut,
+    deps: DepsMut,
     _env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
+    BID_FEE_RATE.save(deps.storage, &Decimal::percent(1))?;
     Ok(Response::new())
 }
+
+// Q15: Unvalidated funds — any denom is credited, and indexing panics when no funds are sent
+// Q42: Unchecked deposit denom — the balance increment takes info.funds[0].amount as is
+fn execute_deposit(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let amount = info.funds[0].amount;
+    BALANCES.update(deps.storage, &info.sender, |bal| -> Result<_, ContractError> {
+        Ok(bal.unwrap_or_default().checked_add(amount)
+            .map_err(|_| ContractError::Std(cosmwasm_std::StdError::generic_err("overflow")))?)
+    })?;
+    Ok(Response::new().add_attribute("action", "deposit"))
+}
+
+// Q42: Unchecked deposit denom — every attached coin counts toward the stake
+// (Q4: with a raw `+=`)
+fn execute_stake(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let mut stake = STAKES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    for coin in info.funds.iter() {
+        stake += coin.amount;
+    }
+    STAKES.save(deps.storage, &info.sender, &stake)?;
+    Ok(Response::new().add_attribute("action", "stake"))
+}
+
+// Q4: Compound assignment on Uint128 — `-=` instead of checked_sub (Q31: funds ignored)
+// Q19: Send to unvalidated address — recipient comes straight from the message
+// Q21: Missing zero-amount check — a zero withdrawal still emits a BankMsg
+fn execute_withdraw(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let mut balance = BALANCES.load(deps.storage, &info.sender)?;
+    balance -= amount;
+    BALANCES.save(deps.storage, &info.sender, &balance)?;
+    Ok(Response::new().add_message(BankMsg::Send {
+        to_address: recipient,
+        amount: coins(amount.u128(), "uatom"),
+    }))
+}
+
+// Q17: Nondeterministic iteration — HashMap order decides the order of BankMsg sends
+pub fn execute_distribute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    payouts: Vec<(String, Uint128)>,
+) -> Result<Response, ContractError> {
+    let mut totals: HashMap<String, Uint128> = HashMap::new();
+    for (recipient, amount) in payouts {
+        let total = totals.entry(recipient).or_default();
+        *total = total.checked_add(amount)
+            .map_err(|_| ContractError::Std(cosmwasm_std::StdError::generic_err("overflow")))?;
+    }
+    let mut response = Response::new();
+    for (recipient, amount) in totals {
+        response = response.add_message(BankMsg::Send {
+            to_address: recipient,
+            amount: coins(amount.u128(), "uatom"),
+        });
+    }
+    Ok(response)
+}
@@ -0,0 +1,34 @@
+use cosmwasm_std::{coins, to_json_binary, BankMsg, DepsMut, Response, Uint128, WasmMsg};
+use crate::error::ContractError;
+use crate::msg::{AdminResponse, CouncilExecuteMsg, CouncilQueryMsg, TreasuryResponse};
+use crate::state::COUNCIL;
+
+pub fn execute_escalate(deps: DepsMut, proposal_id: u64) -> Result<Response, ContractError> {
+    let council = COUNCIL.load(deps.storage)?;
+    let res: AdminResponse =
+        deps.querier.query_wasm_smart(&council, &CouncilQueryMsg::Admin {})?;
+    // Q75: Untrusted query address — the council decides where this message goes
+    let msg = WasmMsg::Execute {
+        contract_addr: res.admin.clone(),
+        msg: to_json_binary(&CouncilExecuteMsg::Review { proposal_id })?,
+        funds: vec![],
+    };
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "escalate")
+        .add_attribute("admin", res.admin))
+}
+
+// Q21: Missing zero-amount check — a zero sweep still emits a BankMsg
+pub fn execute_sweep_fees(deps: DepsMut, amount: Uint128) -> Result<Response, ContractError> {
+    let council = COUNCIL.load(deps.storage)?;
+    let res: TreasuryResponse =
+        deps.querier.query_wasm_smart(&council, &CouncilQueryMsg::Treasury {})?;
+    let treasury = res.treasury;
+    // Q75: deserializing into Addr does not validate the address
+    let msg = BankMsg::Send {
+        to_address: treasury.to_string(),
+        amount: coins(amount.u128(), "uatom"),
+    };
+    Ok(Response::new().add_message(msg).add_attribute("action", "sweep_fees"))
+}
@@ -0,0 +1,22 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, StdResult, Uint128};
+use crate::error::ContractError;
+use crate::state::CREDITS;
+
+// Q21: Missing zero-amount check — a zero gift is accepted (Q31: funds ignored)
+pub fn execute_gift(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    // Not flagged: info.sender is already normalized
+    CREDITS.update(deps.storage, info.sender.to_string(), |credit| -> StdResult<_> {
+        Ok(credit.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    // Q67: Unnormalized map key — "cosmos1ABC.." and "cosmos1abc.." are credited
+    // to different entries
+    CREDITS.update(deps.storage, recipient, |credit| -> StdResult<_> {
+        Ok(credit.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(Response::new().add_attribute("action", "gift"))
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult};
+use crate::error::ContractError;
+use crate::state::{DELEGATES, DELEGATE_CHANGED_AT};
+
+// Q31: Unexpected funds — coins sent with the change are locked
+pub fn execute_set_delegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    // Q74: Swallowed error — an invalid address is skipped, yet the call succeeds
+    let validated = deps.api.addr_validate(&delegate).ok();
+    if let Some(addr) = validated {
+        DELEGATES.save(deps.storage, &info.sender, &addr)?;
+    }
+    // Q74: the change record is best-effort, so its error is dropped
+    let _ = record_change(deps, &info.sender, env.block.height);
+    Ok(Response::new().add_attribute("action", "set_delegate"))
+}
+
+fn record_change(deps: DepsMut, sender: &Addr, height: u64) -> StdResult<()> {
+    DELEGATE_CHANGED_AT.save(deps.storage, sender, &height)
+}
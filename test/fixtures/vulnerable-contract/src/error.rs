@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -8,4 +8,22 @@ pub enum ContractError {
 
     #[error("Unauthorized")]
     Unauthorized {},
+
+    #[error("Batch of {size} exceeds the maximum of {max}")]
+    BatchTooLarge { size: usize, max: usize },
+
+    #[error("Already claimed in this block")]
+    AlreadyClaimed {},
+
+    #[error("Name is longer than {max} bytes")]
+    NameTooLong { max: usize },
+
+    #[error("Bid is above the maximum of {max}")]
+    BidTooHigh { max: Uint128 },
+
+    #[error("The claim window has closed")]
+    ClaimExpired {},
+
+    #[error("Funds are still locked")]
+    Locked {},
 }
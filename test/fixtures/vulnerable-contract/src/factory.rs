@@ -0,0 +1,56 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, WasmMsg};
+use cw_storage_plus::Item;
+use crate::error::ContractError;
+
+pub const POOL_CODE_ID: Item<u64> = Item::new("pool_code_id");
+pub const POOL_SALT: &[u8] = b"pool";
+
+#[cw_serde]
+pub struct PoolInstantiateMsg {
+    pub creator: String,
+}
+
+// Q40: Instantiate without admin — the pool can never be migrated
+// (Q40: and the constant salt makes its address predictable)
+pub fn execute_create_pool(
+    deps: DepsMut,
+    info: MessageInfo,
+    label: String,
+) -> Result<Response, ContractError> {
+    let code_id = POOL_CODE_ID.load(deps.storage)?;
+    let instantiate = WasmMsg::Instantiate2 {
+        admin: None,
+        code_id,
+        label,
+        msg: to_json_binary(&PoolInstantiateMsg {
+            creator: info.sender.to_string(),
+        })?,
+        funds: vec![],
+        salt: Binary::from(POOL_SALT),
+    };
+    Ok(Response::new().add_message(instantiate))
+}
+
+// Q80: Serialization unwrap — the Instantiate payload is serialized with .expect(),
+// bound to a local first
+pub fn execute_spawn_child(
+    deps: Deps,
+    env: Env,
+    info: MessageInfo,
+    label: String,
+) -> Result<Response, ContractError> {
+    let code_id = POOL_CODE_ID.load(deps.storage)?;
+    let init = to_json_binary(&PoolInstantiateMsg {
+        creator: info.sender.to_string(),
+    })
+    .expect("instantiate message serializes");
+    let spawn = WasmMsg::Instantiate {
+        admin: Some(env.contract.address.to_string()),
+        code_id,
+        msg: init,
+        funds: vec![],
+        label,
+    };
+    Ok(Response::new().add_attribute("action", "spawn_child").add_message(spawn))
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::Uint128;
+
+// Q14: Float usage — fee computed with f64 arithmetic (nondeterministic)
+pub fn compute_fee(amount: Uint128) -> Uint128 {
+    let fee = amount.u128() as f64 * 0.03;
+    Uint128::new(fee as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Not flagged: test code never ships on-chain
+    #[test]
+    fn fee_is_three_percent() {
+        let expected = 1000.0 * 0.03;
+        assert_eq!(compute_fee(Uint128::new(1000)).u128() as f64, expected);
+    }
+
+    #[test]
+    fn fee_fits_in_u32() {
+        assert_eq!(compute_fee(Uint128::new(1000)).u128() as u32, 30);
+    }
+}
@@ -0,0 +1,49 @@
+use cosmwasm_std::{BankMsg, DepsMut, Env, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::state::{CLAIMED, GRANTS};
+
+/// Minimum age of a grant before it can be claimed, in seconds
+pub const MIN_GRANT_AGE: u64 = 60;
+
+// Q31: Unexpected funds — coins sent with the claim are locked
+pub fn execute_claim_grant(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let grant = GRANTS.load(deps.storage, &info.sender)?;
+    // Not reported: the grant age is not an expiry
+    if grant.created_at.plus_seconds(MIN_GRANT_AGE) > env.block.time {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Q70: Expiry boundary — expires_at is documented as inclusive, but a claim at exactly
+    // env.block.time == expires_at is rejected; '<=' was intended
+    let open = env.block.time < grant.expires_at;
+    if !open {
+        return Err(ContractError::ClaimExpired {});
+    }
+    GRANTS.remove(deps.storage, &info.sender);
+    CLAIMED.save(deps.storage, &info.sender, &grant.amount)?;
+    Ok(Response::new().add_attribute("action", "claim_grant"))
+}
+
+pub fn execute_withdraw_grant(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let grant = GRANTS.load(deps.storage, &info.sender)?;
+    // Reviewed: withdrawals open at unlock_at itself, so '<' is right here
+    // cosmwasm-codeql: ignore expiry-boundary reason="unlock_at is the first unlocked instant"
+    if env.block.time < grant.unlock_at {
+        return Err(ContractError::Locked {});
+    }
+    let amount = CLAIMED.load(deps.storage, &info.sender)?;
+    CLAIMED.remove(deps.storage, &info.sender);
+    Ok(Response::new()
+        .add_attribute("action", "withdraw_grant")
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![amount],
+        }))
+}
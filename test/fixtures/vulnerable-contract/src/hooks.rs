@@ -0,0 +1,32 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::msg::{Cw721ReceiveMsg, MemberChangedHookMsg};
+use crate::state::{DEPOSITED_NFTS, WEIGHTS};
+
+// Q76: Sender type assumption — anyone can rewrite the weights by sending the hook
+// themselves (Q1: no authorization, Q31: funds ignored)
+pub fn execute_member_changed(
+    deps: DepsMut,
+    hook: MemberChangedHookMsg,
+) -> Result<Response, ContractError> {
+    for diff in hook.diffs {
+        match diff.new {
+            Some(weight) => WEIGHTS.save(deps.storage, &diff.key, &weight)?,
+            None => WEIGHTS.remove(deps.storage, &diff.key),
+        }
+    }
+    Ok(Response::new().add_attribute("action", "member_changed"))
+}
+
+// Q76: any contract or account can claim to have deposited any token_id
+// (Q1: no authorization, Q31: funds ignored)
+pub fn execute_deposit_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    receive: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    DEPOSITED_NFTS.save(deps.storage, &receive.token_id, &receive.sender)?;
+    Ok(Response::new()
+        .add_attribute("action", "deposit_nft")
+        .add_attribute("collection", info.sender))
+}
@@ -7,6 +7,7 @@ use crate::error::ContractError;
 use crate::state::CONFIG;
 
 // Q8: IBC CEI violation — state change + message dispatch
+// Q19: Hardcoded recipient — refund goes to a fixed "sender" string (Q30: with a hardcoded 100uatom)
 #[entry_point]
 pub fn ibc_packet_timeout(
     deps: DepsMut,
@@ -23,6 +24,7 @@ pub fn ibc_packet_timeout(
 }
 
 // Q9: SubMsg with reply but no reply handler exists
+// Q19: Hardcoded recipient — contract address is a literal
 pub fn execute_swap(
     _deps: DepsMut,
     _env: Env,
@@ -0,0 +1,17 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::state::PARTICIPANTS;
+
+// Q18: Block data as randomness — the proposer can pick a block time that
+// selects a winner of their choice. No safe fixture: on-chain randomness
+// requires an external beacon.
+pub fn execute_draw(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let participants = PARTICIPANTS.load(deps.storage)?;
+    let winner_index = env.block.time.nanos() % participants.len() as u64;
+    let winner = &participants[winner_index as usize];
+    Ok(Response::new().add_attribute("winner", winner.to_string()))
+}
@@ -1,20 +1,153 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
+use cw20::Cw20ReceiveMsg;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub admin: String,
+    pub recipients: Vec<String>,
+    pub oracle: String,
+    pub council: String,
+    pub beneficiary: Option<String>,
+    /// Initial supply as a decimal string, for clients without u128 support
+    pub initial_supply: String,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     UpdateConfig { new_admin: String },
     Mint { amount: Uint128, recipient: String },
+    Pause {},
+    Withdraw { amount: Uint128, recipient: String },
+    SetPrice { numerator: Uint128, denominator: Uint128 },
+    Swap { amount: Uint128 },
+    Claim { reward: Decimal },
+    Deposit {},
+    Stake {},
+    Start { amount: Uint128 },
+    InternalFinalize {},
+    ApplySettlement {},
+    Receive(Cw20ReceiveMsg),
+    Payout { recipient: String, principal: Uint128, interest: Uint128 },
+    Forward { contract: String, stake: Uint128, fee: Uint128 },
+    ResetStake {},
+    Compound {},
+    Split { amounts: Vec<Uint128> },
+    Pay { payments: Vec<Payment> },
+    Transfer { recipient: String, amount: Uint128 },
+    Unbond { amount: Uint128 },
+    ClaimRewards { weights: Vec<Uint128> },
+    Register { name: String },
+    CreateListing { id: String, price: Uint128 },
+    Buy { items: Vec<String> },
+    Sweep { denoms: Vec<String> },
+    Airdrop { recipient: String },
+    MintReward { recipient: String },
+    Snapshot {},
+    Touch {},
+    Unstake { amount: Uint128 },
+    Bid { amount: Uint128 },
+    Gift { recipient: String, amount: Uint128 },
+    Restake {},
+    Burn { amount_str: String },
+    SetDelegate { delegate: String },
+    Collect {},
+    Escalate { proposal_id: u64 },
+    SweepFees { amount: Uint128 },
+    MemberChangedHook(MemberChangedHookMsg),
+    DepositNft(Cw721ReceiveMsg),
+    ClaimGrant {},
+    WithdrawGrant {},
+    Redeem { amount: Uint128 },
+    Accrue {},
+    Ping { amount: Uint128 },
+    Pong { amount: Uint128 },
+    Relay { contract: String },
+    SpawnChild { label: String },
+}
+
+#[cw_serde]
+pub struct Payment {
+    pub recipient: String,
+    pub amount: Uint128,
+}
+
+/// Sent by the cw4 group contract when its membership changes
+#[cw_serde]
+pub struct MemberChangedHookMsg {
+    pub diffs: Vec<MemberDiff>,
+}
+
+#[cw_serde]
+pub struct MemberDiff {
+    pub key: String,
+    pub new: Option<u64>,
+}
+
+/// Sent by a cw721 contract to the recipient of `send_nft`
+#[cw_serde]
+pub struct Cw721ReceiveMsg {
+    pub sender: String,
+    pub token_id: String,
+    pub msg: Binary,
+}
+
+#[cw_serde]
+pub enum HookMsg {
+    Deposit {},
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    Deposit {},
+    Stake {},
+}
+
+#[cw_serde]
+pub enum OracleQueryMsg {
+    Price { item: String },
+}
+
+#[cw_serde]
+pub struct PriceResponse {
+    pub price: Uint128,
+}
+
+#[cw_serde]
+pub enum CouncilQueryMsg {
+    Admin {},
+    Treasury {},
+}
+
+#[cw_serde]
+pub struct AdminResponse {
+    pub admin: String,
+}
+
+#[cw_serde]
+pub struct TreasuryResponse {
+    pub treasury: Addr,
+}
+
+#[cw_serde]
+pub enum CouncilExecuteMsg {
+    Review { proposal_id: u64 },
 }
 
 #[cw_serde]
 pub enum QueryMsg {
     Config {},
+    ListProposals {},
+    Proposal { id: u64 },
+    Price {},
+    ListBalances { start_after: Option<String>, limit: Option<u32> },
+    ListClaims { start_after: Option<u64>, limit: Option<u32> },
+    Credit { address: String },
+    Reserves {},
+    Totals {},
+    ListStakes { start_after: Option<String>, limit: Option<u32> },
+    ListRewards { start_after: Option<String>, limit: Option<u32> },
+    ProposalPage { start_after: Option<String>, limit: Option<u32> },
 }
 
 #[cw_serde]
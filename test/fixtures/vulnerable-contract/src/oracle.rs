@@ -0,0 +1,42 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, Deps, DepsMut, Env, Response, StdError, StdResult, Uint128,
+};
+use crate::error::ContractError;
+use crate::msg::{OracleQueryMsg, PriceResponse};
+use crate::state::ORACLE;
+
+pub fn execute_buy(deps: DepsMut, items: Vec<String>) -> Result<Response, ContractError> {
+    let oracle = ORACLE.load(deps.storage)?;
+    let mut total = Uint128::zero();
+    // Q59: one oracle query per item, and the caller picks how many items (Q36: unbounded input)
+    for item in items.iter() {
+        // Q25: Untrusted query result — each price is summed with no bounds
+        let res: PriceResponse = deps.querier.query_wasm_smart(
+            &oracle,
+            &to_json_binary(&OracleQueryMsg::Price { item: item.clone() })?,
+        )?;
+        total = total.checked_add(res.price).map_err(StdError::overflow)?;
+    }
+    Ok(Response::new().add_attribute("action", "buy").add_attribute("total", total))
+}
+
+pub fn execute_sweep(
+    deps: DepsMut,
+    env: Env,
+    denoms: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut balances = vec![];
+    let mut i = 0;
+    // Q59: a bank query per denom, through a helper
+    while i < denoms.len() {
+        balances.push(balance_of(deps.as_ref(), &env.contract.address, &denoms[i])?);
+        i += 1;
+    }
+    Ok(Response::new()
+        .add_attribute("action", "sweep")
+        .add_attribute("swept", balances.len().to_string()))
+}
+
+fn balance_of(deps: Deps, address: &Addr, denom: &str) -> StdResult<Coin> {
+    deps.querier.query_balance(address, denom)
+}
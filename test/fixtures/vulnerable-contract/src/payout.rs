@@ -0,0 +1,54 @@
+use cosmwasm_std::{coin, to_json_binary, BankMsg, Coin, Deps, Response, Uint128, WasmMsg};
+use crate::error::ContractError;
+use crate::msg::HookMsg;
+
+pub const STAKE_DENOM: &str = "ustake";
+pub const FEE_DENOM: &str = "uatom";
+
+pub fn execute_payout(
+    deps: Deps,
+    recipient: String,
+    principal: Uint128,
+    interest: Uint128,
+) -> Result<Response, ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    // Q53: two uatom coins in one send
+    let send = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: vec![coin(principal.u128(), "uatom"), Coin::new(interest, "uatom")],
+    };
+    Ok(Response::new().add_attribute("action", "payout").add_message(send))
+}
+
+pub fn execute_forward(
+    deps: Deps,
+    contract: String,
+    stake: Uint128,
+    fee: Uint128,
+) -> Result<Response, ContractError> {
+    let contract = deps.api.addr_validate(&contract)?;
+    // Q53: ustake sorts after uatom; the constants hide the order
+    let funds = vec![coin(stake.u128(), STAKE_DENOM), coin(fee.u128(), FEE_DENOM)];
+    let forward = WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(&HookMsg::Deposit {})?,
+        funds,
+    };
+    Ok(Response::new().add_attribute("action", "forward").add_message(forward))
+}
+
+// Q80: Serialization unwrap — the Execute payload is serialized with .unwrap(), panicking
+// on failure
+pub fn execute_relay(deps: Deps, contract: String) -> Result<Response, ContractError> {
+    let contract = deps.api.addr_validate(&contract)?;
+    let relay = WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(&HookMsg::Deposit {}).unwrap(),
+        funds: vec![],
+    };
+    // Not reported: response data, not the payload of a message
+    Ok(Response::new()
+        .add_attribute("action", "relay")
+        .set_data(to_json_binary(&contract).unwrap())
+        .add_message(relay))
+}
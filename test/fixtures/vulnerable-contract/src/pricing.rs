@@ -0,0 +1,34 @@
+use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Response, Uint128};
+use crate::error::ContractError;
+use crate::state::{BALANCES, PRICE};
+
+// Q39: Decimal conversion hazard (division by zero) — a zero denominator
+// from the message panics inside from_ratio (Q1: anyone can set the price, Q31: funds ignored)
+pub fn execute_set_price(
+    deps: DepsMut,
+    numerator: Uint128,
+    denominator: Uint128,
+) -> Result<Response, ContractError> {
+    PRICE.save(deps.storage, &Decimal::from_ratio(numerator, denominator))?;
+    Ok(Response::new().add_attribute("action", "set_price"))
+}
+
+// Q39: Decimal conversion hazard (overflow) — mul_floor panics when the
+// product exceeds Uint128
+pub fn execute_swap(deps: DepsMut, amount: Uint128) -> Result<Response, ContractError> {
+    let price: Decimal = PRICE.may_load(deps.storage)?.unwrap_or(Decimal::one());
+    let out = amount.mul_floor(price);
+    Ok(Response::new().add_attribute("out", out))
+}
+
+// Q39: Decimal conversion hazard (overflow) — mul_ceil by a sender-chosen
+// reward rate panics when the payout exceeds Uint128
+pub fn execute_claim(
+    deps: DepsMut,
+    info: MessageInfo,
+    reward: Decimal,
+) -> Result<Response, ContractError> {
+    let stake = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    let payout = stake.mul_ceil(reward);
+    Ok(Response::new().add_attribute("payout", payout))
+}
@@ -0,0 +1,159 @@
+use cosmwasm_std::{
+    entry_point, to_json_binary, Addr, Binary, Deps, Env, Order, StdError, StdResult, Uint128,
+};
+use cw_storage_plus::Bound;
+use crate::msg::QueryMsg;
+use crate::state::{
+    BALANCES, CLAIMS, CONFIG, CREDITS, PROPOSALS, RESERVE_A, RESERVE_B, REWARDS, STAKES,
+};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+/// Proposal cursors are returned to clients as "proposal-<id>"
+const PROPOSAL_CURSOR_PREFIX: &str = "proposal-";
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::ListProposals {} => to_json_binary(&query_proposals(deps)?),
+        QueryMsg::Proposal { id } => to_json_binary(&query_proposal(deps, id)?),
+        QueryMsg::Price {} => to_json_binary(&query_price(deps)?),
+        QueryMsg::ListBalances { start_after, limit } => {
+            to_json_binary(&query_balances(deps, start_after, limit)?)
+        }
+        QueryMsg::ListClaims { start_after, limit } => {
+            to_json_binary(&query_claims(deps, start_after, limit)?)
+        }
+        QueryMsg::Credit { address } => to_json_binary(&query_credit(deps, address)?),
+        QueryMsg::Reserves {} => query_reserves(deps),
+        QueryMsg::Totals {} => query_totals(deps),
+        QueryMsg::ListStakes { start_after, limit } => {
+            to_json_binary(&query_stakes(deps, start_after, limit)?)
+        }
+        QueryMsg::ListRewards { start_after, limit } => {
+            to_json_binary(&query_rewards(deps, start_after, limit)?)
+        }
+        QueryMsg::ProposalPage { start_after, limit } => {
+            to_json_binary(&query_proposal_page(deps, start_after, limit)?)
+        }
+    }
+}
+
+// Q5: Panicking expect on Map::load — a missing id aborts instead of erroring
+fn query_proposal(deps: Deps, id: u64) -> StdResult<String> {
+    let proposal = PROPOSALS.load(deps.storage, id).expect("proposal exists");
+    Ok(proposal.title)
+}
+
+// Q13: Unbounded map iteration — loads every proposal in a single query
+fn query_proposals(deps: Deps) -> StdResult<Vec<u64>> {
+    PROPOSALS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(id, _)| id))
+        .collect()
+}
+
+// Q20: Division by zero — panics once the B reserve is drained
+fn query_price(deps: Deps) -> StdResult<Uint128> {
+    let pool_a = RESERVE_A.load(deps.storage)?;
+    let pool_b = RESERVE_B.load(deps.storage)?;
+    Ok(pool_a / pool_b)
+}
+
+// Q51: Unbounded pagination — the caller's limit only gets a default
+fn query_balances(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+    let start = start_after.map(|s| deps.api.addr_validate(&s)).transpose()?;
+    BALANCES
+        .range(deps.storage, start.as_ref().map(Bound::exclusive), None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+// Q51: Unbounded pagination — converted inline, never clamped
+fn query_claims(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(u64, Uint128)>> {
+    CLAIMS
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit.unwrap_or_default() as usize)
+        .collect()
+}
+
+fn query_credit(deps: Deps, address: String) -> StdResult<Uint128> {
+    // Q67: a differently written address reads an empty credit
+    Ok(CREDITS.may_load(deps.storage, address)?.unwrap_or_default())
+}
+
+// Q73: Untyped query response — serialized as a JSON array, with no schema for clients
+fn query_reserves(deps: Deps) -> StdResult<Binary> {
+    let reserve_a = RESERVE_A.load(deps.storage)?;
+    let reserve_b = RESERVE_B.load(deps.storage)?;
+    to_json_binary(&(reserve_a, reserve_b))
+}
+
+// Q73: the response struct is local to the handler and has no schema
+fn query_totals(deps: Deps) -> StdResult<Binary> {
+    #[derive(cosmwasm_schema::serde::Serialize)]
+    #[serde(crate = "cosmwasm_schema::serde")]
+    struct Totals {
+        supply: Uint128,
+        reserve_a: Uint128,
+    }
+
+    let totals = Totals {
+        supply: CONFIG.load(deps.storage)?.total_supply,
+        reserve_a: RESERVE_A.load(deps.storage)?,
+    };
+    to_json_binary(&totals)
+}
+
+fn query_stakes(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // Q77: Unchecked pagination cursor — a wrongly-cased cursor starts the page at the
+    // wrong key
+    let start_addr = start_after.map(Addr::unchecked);
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    STAKES.range(deps.storage, start, None, Order::Ascending).take(limit).collect()
+}
+
+fn query_rewards(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // Q77: the cursor is never validated
+    let start_addr = start_after.as_ref().map(|s| Addr::unchecked(s));
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    REWARDS.range(deps.storage, start, None, Order::Ascending).take(limit).collect()
+}
+
+fn query_proposal_page(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<u64>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // Q77: panics on a cursor shorter than the prefix
+    let start = start_after
+        .map(|cursor| cursor[PROPOSAL_CURSOR_PREFIX.len()..].parse::<u64>())
+        .transpose()
+        .map_err(|_| StdError::generic_err("invalid cursor"))?
+        .map(Bound::exclusive);
+    PROPOSALS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
@@ -0,0 +1,45 @@
+use cosmwasm_std::{from_json, DepsMut, MessageInfo, Response, StdError};
+use cw20::Cw20ReceiveMsg;
+use crate::error::ContractError;
+use crate::msg::ReceiveMsg;
+use crate::state::{BALANCES, CW20_TOKEN, STAKES};
+
+pub fn execute_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match from_json(&wrapper.msg)? {
+        ReceiveMsg::Deposit {} => receive_deposit(deps, info, wrapper),
+        ReceiveMsg::Stake {} => receive_stake(deps, info, wrapper),
+    }
+}
+
+// Q47: Unvalidated cw20 receive — any contract can send Receive and be credited
+fn receive_deposit(
+    deps: DepsMut,
+    _info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let depositor = deps.api.addr_validate(&wrapper.sender)?;
+    BALANCES.update(deps.storage, &depositor, |bal| -> Result<_, ContractError> {
+        Ok(bal.unwrap_or_default().checked_add(wrapper.amount).map_err(StdError::from)?)
+    })?;
+    Ok(Response::new().add_attribute("action", "receive_deposit"))
+}
+
+// Q47: Unvalidated cw20 receive — the amount is read before the token check
+fn receive_stake(
+    deps: DepsMut,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let amount = wrapper.amount;
+    let token = CW20_TOKEN.may_load(deps.storage)?.ok_or(ContractError::Unauthorized {})?;
+    if info.sender != token {
+        return Err(ContractError::Unauthorized {});
+    }
+    let staker = deps.api.addr_validate(&wrapper.sender)?;
+    STAKES.save(deps.storage, &staker, &amount)?;
+    Ok(Response::new().add_attribute("action", "receive_stake"))
+}
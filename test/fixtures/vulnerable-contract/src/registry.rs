@@ -0,0 +1,34 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+use crate::error::ContractError;
+use crate::state::{Listing, LISTINGS, NAMES};
+
+pub const MAX_NAME_LENGTH: usize = 64;
+
+// Q31: Unexpected funds — the registration fee is never checked, attached coins are locked
+pub fn execute_register(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    if name.len() > MAX_NAME_LENGTH {
+        return Err(ContractError::NameTooLong { max: MAX_NAME_LENGTH });
+    }
+    // Q58: a name that is already taken is handed to the new caller
+    NAMES.save(deps.storage, &name, &info.sender)?;
+    Ok(Response::new().add_attribute("action", "register").add_attribute("name", name))
+}
+
+// Q31: Unexpected funds — coins sent with the listing are locked
+pub fn execute_create_listing(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: String,
+    price: Uint128,
+) -> Result<Response, ContractError> {
+    if id.len() > MAX_NAME_LENGTH {
+        return Err(ContractError::NameTooLong { max: MAX_NAME_LENGTH });
+    }
+    // Q58: replaces another seller's listing with the same id
+    LISTINGS.save(deps.storage, &id, &Listing { seller: info.sender, price })?;
+    Ok(Response::new().add_attribute("action", "create_listing"))
+}
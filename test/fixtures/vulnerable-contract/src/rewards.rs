@@ -0,0 +1,73 @@
+use cosmwasm_std::{
+    coins, BankMsg, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Uint128,
+};
+use crate::error::ContractError;
+use crate::state::{LAST_CLAIM, REWARDS, STAKES};
+
+// Q57: Panicking update closure — panics for a sender with no stake instead of returning
+// an error (Q21: a zero amount is accepted, Q31: funds ignored)
+pub fn execute_unbond(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    STAKES.update(deps.storage, &info.sender, |s| -> StdResult<_> { Ok(s.unwrap() - amount) })?;
+    Ok(Response::new().add_attribute("action", "unbond"))
+}
+
+// Q31: Unexpected funds — coins sent with the claim are locked
+pub fn execute_claim_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    weights: Vec<Uint128>,
+) -> Result<Response, ContractError> {
+    // Q57: an empty weights list panics inside the closure
+    REWARDS.update(deps.storage, &info.sender, |r| -> StdResult<_> {
+        Ok(r.unwrap_or_default().checked_add(weights[0])?)
+    })?;
+    // Q57: panics on the sender's first claim, before any is recorded
+    LAST_CLAIM.update(deps.storage, &info.sender, |last| -> Result<_, ContractError> {
+        let previous = last.expect("claimed before");
+        if previous >= env.block.height {
+            return Err(ContractError::AlreadyClaimed {});
+        }
+        Ok(env.block.height)
+    })?;
+    Ok(Response::new().add_attribute("action", "claim_rewards"))
+}
+
+// Q68: Generic error overuse — the handler has typed errors, but an empty reward is
+// still a generic_err (Q31: funds ignored)
+pub fn execute_restake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if LAST_CLAIM.may_load(deps.storage, &info.sender)? == Some(env.block.height) {
+        return Err(ContractError::AlreadyClaimed {});
+    }
+    let reward = REWARDS.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    if reward.is_zero() {
+        return Err(StdError::generic_err("nothing to restake").into());
+    }
+    REWARDS.remove(deps.storage, &info.sender);
+    STAKES.update(deps.storage, &info.sender, |s| -> StdResult<_> {
+        Ok(s.unwrap_or_default().checked_add(reward)?)
+    })?;
+    LAST_CLAIM.save(deps.storage, &info.sender, &env.block.height)?;
+    Ok(Response::new().add_attribute("action", "restake"))
+}
+
+pub fn execute_collect(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let mut response = Response::new().add_attribute("action", "collect");
+    // Q74: Swallowed error — a corrupt or missing entry makes the collect a silent no-op
+    if let Ok(reward) = REWARDS.load(deps.storage, &info.sender) {
+        REWARDS.remove(deps.storage, &info.sender);
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(reward.u128(), "uatom"),
+        });
+    }
+    Ok(response)
+}
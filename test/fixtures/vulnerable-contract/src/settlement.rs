@@ -0,0 +1,105 @@
+use cosmwasm_std::{
+    coins, to_json_binary, BankMsg, Deps, DepsMut, Env, MessageInfo, Response, StdError, Uint128,
+    WasmMsg,
+};
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::state::{CONFIG, PENDING, SETTLED};
+
+// Q1: Missing authorization — anyone can start a settlement (Q21: of zero, Q31: funds ignored)
+pub fn execute_start(deps: DepsMut, env: Env, amount: Uint128) -> Result<Response, ContractError> {
+    PENDING.save(deps.storage, &amount)?;
+    let finalize = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::InternalFinalize {})?,
+        funds: vec![],
+    };
+    let settle = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::ApplySettlement {})?,
+        funds: vec![],
+    };
+    Ok(Response::new()
+        .add_attribute("action", "start")
+        .add_message(finalize)
+        .add_message(settle))
+}
+
+// Q46: Missing self-call guard — the internal step is callable by anyone
+// (Q1: and writes state, Q31: funds ignored)
+pub fn execute_internal_finalize(deps: DepsMut) -> Result<Response, ContractError> {
+    let pending = PENDING.may_load(deps.storage)?.unwrap_or_default();
+    PENDING.remove(deps.storage);
+    SETTLED.save(deps.storage, &pending)?;
+    Ok(Response::new().add_attribute("action", "finalize"))
+}
+
+// Q46: Missing self-call guard — only sent by the contract to itself, but
+// never checked: anyone can trigger the payout early
+pub fn execute_apply_settlement(deps: Deps) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let settled = SETTLED.may_load(deps.storage)?.unwrap_or_default();
+    Ok(Response::new()
+        .add_attribute("action", "apply_settlement")
+        .add_message(BankMsg::Send {
+            to_address: config.admin.to_string(),
+            amount: coins(settled.u128(), "uatom"),
+        }))
+}
+
+// Q79: Unbounded self-recursion — Accrue re-dispatches Accrue unconditionally, so it
+// never stops (Q1: and writes state, Q46: anyone can start it, Q31: funds ignored)
+pub fn execute_accrue(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let pending = PENDING.may_load(deps.storage)?.unwrap_or_default();
+    PENDING.remove(deps.storage);
+    let settled = SETTLED.may_load(deps.storage)?.unwrap_or_default();
+    let settled = settled.checked_add(pending).map_err(StdError::overflow)?;
+    SETTLED.save(deps.storage, &settled)?;
+    let again = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::Accrue {})?,
+        funds: vec![],
+    };
+    Ok(Response::new().add_attribute("action", "accrue").add_message(again))
+}
+
+// Q79: Unbounded self-recursion — Ping sends Pong, which sends Ping back, with nothing
+// counting the rounds (Q21: of zero, Q31: funds ignored)
+pub fn execute_ping(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    PENDING.save(deps.storage, &amount)?;
+    let this = env.contract.address.to_string();
+    let pong = WasmMsg::Execute {
+        contract_addr: this,
+        msg: to_json_binary(&ExecuteMsg::Pong { amount })?,
+        funds: vec![],
+    };
+    Ok(Response::new().add_attribute("action", "ping").add_message(pong))
+}
+
+// Q79: Unbounded self-recursion — the other half of the Ping/Pong cycle
+// (Q21: of zero, Q31: funds ignored)
+pub fn execute_pong(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    SETTLED.save(deps.storage, &amount)?;
+    let ping = ExecuteMsg::Ping { amount };
+    Ok(Response::new().add_attribute("action", "pong").add_message(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ping)?,
+        funds: vec![],
+    }))
+}
@@ -0,0 +1,23 @@
+use cosmwasm_std::{from_json, DepsMut, MessageInfo, Response, Uint128};
+use crate::error::ContractError;
+
+pub fn execute_snapshot(deps: DepsMut) -> Result<Response, ContractError> {
+    // Q63: Raw storage access — reads the key of the CONFIG Item
+    if let Some(bytes) = deps.storage.get(b"config") {
+        // Q63: raw write to a hand-built key
+        deps.storage.set(b"config_snapshot", &bytes);
+    }
+    Ok(Response::new().add_attribute("action", "snapshot"))
+}
+
+pub fn execute_touch(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    // Q63: raw read of an entry in the namespace of the BALANCES Map
+    let balance: Uint128 = match deps.storage.get(b"\x00\x03baladmin") {
+        Some(bytes) => from_json(&bytes)?,
+        None => Uint128::zero(),
+    };
+    let key = [b"touched".as_slice(), info.sender.as_bytes()].concat();
+    // Q63: raw write to a hand-built key
+    deps.storage.set(&key, &balance.u128().to_be_bytes());
+    Ok(Response::new().add_attribute("action", "touch"))
+}
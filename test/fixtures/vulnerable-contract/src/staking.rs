@@ -0,0 +1,73 @@
+use cosmwasm_std::{
+    coins, BankMsg, DepsMut, Env, MessageInfo, Response, StdError, StdResult, SubMsg, Uint128,
+};
+use crate::error::ContractError;
+use crate::state::{BALANCES, STAKES};
+
+pub const PAYOUT_REPLY_ID: u64 = 2;
+
+// Q54: Ignored result — a failed save is silently ignored (Q31: and funds are ignored)
+pub fn execute_reset_stake(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let _ = STAKES.save(deps.storage, &info.sender, &Uint128::zero());
+    Ok(Response::new().add_attribute("action", "reset_stake"))
+}
+
+// Q54: Ignored result — the overflow error from the closure is dropped with the Result,
+// and the query error is thrown away with .ok() (Q31: funds ignored)
+pub fn execute_compound(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let stake = STAKES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(stake)?)
+    });
+    STAKES.remove(deps.storage, &info.sender);
+    deps.querier.query_balance(env.contract.address, "uatom").ok();
+    Ok(Response::new().add_attribute("action", "compound"))
+}
+
+// Q21: Missing zero-amount check — a zero unstake is accepted (Q31: funds ignored)
+pub fn execute_unstake(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let stake = STAKES.load(deps.storage, &info.sender)?;
+    let stake = stake.checked_sub(amount).map_err(StdError::overflow)?;
+    STAKES.save(deps.storage, &info.sender, &stake)?;
+    // Q65: a failed payout is caught, but both writes around it commit (Q9: no reply handler)
+    let payout = SubMsg::reply_on_error(
+        BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(amount.u128(), "uatom"),
+        },
+        PAYOUT_REPLY_ID,
+    );
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    let balance = balance.checked_add(amount).map_err(StdError::overflow)?;
+    BALANCES.save(deps.storage, &info.sender, &balance)?;
+    Ok(Response::new().add_attribute("action", "unstake").add_submessage(payout))
+}
+
+// Q78: Error type bypass — its only error is a StdError, built in the deduct helper it calls
+// (Q21: a zero redeem is accepted, Q31: funds ignored)
+pub fn execute_redeem(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let stake = STAKES.load(deps.storage, &info.sender)?;
+    let remaining = deduct(stake, amount)?;
+    STAKES.save(deps.storage, &info.sender, &remaining)?;
+    Ok(Response::new()
+        .add_attribute("action", "redeem")
+        .add_attribute("remaining", remaining))
+}
+
+fn deduct(stake: Uint128, amount: Uint128) -> Result<Uint128, ContractError> {
+    stake
+        .checked_sub(amount)
+        .map_err(|_| ContractError::Std(StdError::generic_err("insufficient stake")))
+}
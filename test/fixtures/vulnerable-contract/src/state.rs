@@ -1,12 +1,100 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 
 pub struct Config {
-    pub admin: Addr,
+    pub admin: Addr, // Q44: Unused admin field — stored, but no execute handler checks it
     pub total_supply: Uint128,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const PAUSED: Item<bool> = Item::new("paused");
 pub const BALANCES: Map<&Addr, Uint128> = Map::new("bal");
 // Q7: Storage key collision — same key "bal" as BALANCES
 pub const BACKUP: Item<Vec<u8>> = Item::new("bal");
+
+pub struct Proposal {
+    pub status: ProposalStatus,
+    pub title: String,
+}
+
+#[derive(PartialEq)]
+pub enum ProposalStatus {
+    Open,
+    Passed,
+    Rejected,
+}
+
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+
+pub const RESERVE_A: Item<Uint128> = Item::new("reserve_a");
+pub const RESERVE_B: Item<Uint128> = Item::new("reserve_b");
+
+pub const PARTICIPANTS: Item<Vec<Addr>> = Item::new("participants");
+
+pub const USERS: Map<&Addr, Uint128> = Map::new("users");
+// Q7: Storage prefix collision — raw Item key is 0x00 0x05 "users" "count",
+// i.e. the USERS entry for key "count" (left over from a PrefixedStorage migration)
+pub const LEGACY_USER_COUNT: Item<u64> = Item::new("\u{0}\u{5}userscount");
+
+pub const PRICE: Item<Decimal> = Item::new("price");
+pub const STAKES: Map<&Addr, Uint128> = Map::new("stakes");
+
+pub const PENDING: Item<Uint128> = Item::new("pending");
+pub const SETTLED: Item<Uint128> = Item::new("settled");
+pub const CW20_TOKEN: Item<Addr> = Item::new("cw20_token");
+
+pub const CLAIMS: Map<u64, Uint128> = Map::new("claims");
+pub const REWARDS: Map<&Addr, Uint128> = Map::new("rewards");
+pub const LAST_CLAIM: Map<&Addr, u64> = Map::new("last_claim");
+
+pub struct Listing {
+    pub seller: Addr,
+    pub price: Uint128,
+}
+
+pub const NAMES: Map<&str, Addr> = Map::new("names");
+pub const LISTINGS: Map<&str, Listing> = Map::new("listings");
+pub const ORACLE: Item<Addr> = Item::new("oracle");
+pub const COUNCIL: Item<Addr> = Item::new("council");
+
+pub struct Payees {
+    // Q64: String address in state — the treasury address is kept as a String
+    pub treasury: String,
+    // Q64: the optional beneficiary is kept as a String
+    pub beneficiary: Option<String>,
+}
+
+pub const PAYEES: Item<Payees> = Item::new("payees");
+
+pub struct Params {
+    pub max_bid: Uint128,
+}
+
+// Q66: Incomplete init — loaded by execute, never saved
+pub const PARAMS: Item<Params> = Item::new("params");
+// Q66: saved only by migrate, so new deployments never have it
+pub const BID_FEE_RATE: Item<Decimal> = Item::new("bid_fee_rate");
+// Q66: set by every bid, but the first bid loads it before any save
+pub const LAST_BIDDER: Item<Addr> = Item::new("last_bidder");
+// Not flagged: the only load follows a save in the same handler
+pub const LAST_BID: Item<Uint128> = Item::new("last_bid");
+
+pub const CREDITS: Map<String, Uint128> = Map::new("credits");
+
+pub struct Grant {
+    pub amount: Coin,
+    /// Last instant at which the grant can be claimed, inclusive
+    pub expires_at: Timestamp,
+    /// Claimed funds can be withdrawn from this instant on
+    pub unlock_at: Timestamp,
+    pub created_at: Timestamp,
+}
+
+pub const GRANTS: Map<&Addr, Grant> = Map::new("grants");
+pub const CLAIMED: Map<&Addr, Coin> = Map::new("claimed");
+
+pub const DELEGATES: Map<&Addr, Addr> = Map::new("delegates");
+pub const DELEGATE_CHANGED_AT: Map<&Addr, u64> = Map::new("delegate_changed_at");
+
+pub const WEIGHTS: Map<&str, u64> = Map::new("weights");
+pub const DEPOSITED_NFTS: Map<&str, String> = Map::new("deposited_nfts");
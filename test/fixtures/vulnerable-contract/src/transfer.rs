@@ -0,0 +1,50 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, StdError, StdResult, Uint128};
+use crate::error::ContractError;
+use crate::state::{BALANCES, CONFIG};
+
+// Q56: Self-transfer hazard — both balances are read before either is saved
+// (Q21: a zero amount is accepted, Q31: funds ignored)
+pub fn execute_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let rcpt = deps.api.addr_validate(&recipient)?;
+    let from_balance = BALANCES.load(deps.storage, &info.sender)?;
+    let to_balance = BALANCES.may_load(deps.storage, &rcpt)?.unwrap_or_default();
+    BALANCES.save(
+        deps.storage,
+        &info.sender,
+        &from_balance.checked_sub(amount).map_err(StdError::overflow)?,
+    )?;
+    // Q56: on a self-transfer this overwrites the debit with the old balance plus amount
+    BALANCES.save(
+        deps.storage,
+        &rcpt,
+        &to_balance.checked_add(amount).map_err(StdError::overflow)?,
+    )?;
+    Ok(Response::new().add_attribute("action", "transfer"))
+}
+
+// Q72: Unbounded amount parse — the parse rejects "abc" but not
+// "340282366920938463463374607431768211455" (Q31: funds ignored)
+pub fn execute_burn(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount_str: String,
+) -> Result<Response, ContractError> {
+    let amount = Uint128::new(
+        amount_str
+            .parse::<u128>()
+            .map_err(|e| StdError::parse_err("u128", e))?,
+    );
+    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.total_supply = config.total_supply.checked_sub(amount)?;
+        Ok(config)
+    })?;
+    Ok(Response::new().add_attribute("action", "burn"))
+}
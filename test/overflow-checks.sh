@@ -0,0 +1,82 @@
+#!/bin/bash
+# Records which crates build with `overflow-checks = true` in their release
+# profile, as a data extension for the `overflowChecksEnabled` predicate.
+# Cargo.toml is not extracted into the database, so run this before
+# analysis; crates without a row are treated as wrapping on overflow.
+#
+# Usage:
+#   ./test/overflow-checks.sh <source-root>...            # Writes ext/generated/overflow-checks.model.yml
+#   ./test/overflow-checks.sh <source-root>... --out FILE # Custom output file
+#
+# Members of a Cargo workspace use the profile of the workspace root manifest.
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+PROJECT_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+
+OUT_FILE="$PROJECT_ROOT/ext/generated/overflow-checks.model.yml"
+ROOTS=()
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --out) OUT_FILE="$2"; shift 2 ;;
+    *) ROOTS+=("$1"); shift ;;
+  esac
+done
+
+if [ ${#ROOTS[@]} -eq 0 ]; then
+  echo "usage: overflow-checks.sh <source-root>... [--out FILE]" >&2
+  exit 2
+fi
+
+# Holds if the manifest's [profile.release] section sets overflow-checks = true
+release_overflow_checks() {
+  awk '
+    /^\[/ { in_release = ($0 ~ /^\[profile\.release\][[:space:]]*$/) }
+    in_release && /^[[:space:]]*overflow-checks[[:space:]]*=[[:space:]]*true/ { found = 1 }
+    END { exit !found }
+  ' "$1"
+}
+
+# Prints the manifest whose profiles apply to the package in directory $1:
+# the nearest enclosing [workspace] manifest, or the package's own.
+profile_manifest() {
+  local dir="$1"
+  while [ "$dir" != "/" ]; do
+    if [ -f "$dir/Cargo.toml" ] && grep -q '^\[workspace\]' "$dir/Cargo.toml"; then
+      echo "$dir/Cargo.toml"
+      return
+    fi
+    dir="$(dirname "$dir")"
+  done
+  echo "$1/Cargo.toml"
+}
+
+rows=()
+for root in "${ROOTS[@]}"; do
+  root="$(cd "$root" && pwd)"
+  while IFS= read -r manifest; do
+    grep -q '^\[package\]' "$manifest" || continue
+    crate_dir="$(dirname "$manifest")"
+    if release_overflow_checks "$(profile_manifest "$crate_dir")"; then
+      rows+=("$crate_dir")
+    fi
+  done < <(find "$root" -name Cargo.toml -not -path '*/target/*' -not -path '*/.git/*' | sort)
+done
+
+mkdir -p "$(dirname "$OUT_FILE")"
+{
+  echo "# Generated by test/overflow-checks.sh; do not edit."
+  echo "extensions:"
+  echo "  - addsTo:"
+  echo "      pack: lucasamorimca/cosmwasm-codeql"
+  echo "      extensible: overflowChecksEnabled"
+  if [ ${#rows[@]} -eq 0 ]; then
+    echo "    data: []"
+  else
+    echo "    data:"
+    for row in "${rows[@]}"; do
+      echo "      - [\"$row\"]"
+    done
+  fi
+} > "$OUT_FILE"
+echo "${#rows[@]} crate(s) with overflow-checks: $OUT_FILE" >&2
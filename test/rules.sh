@@ -0,0 +1,275 @@
+#!/bin/bash
+# Rule registry: src/registry/rules.json holds the metadata of every problem
+# query (id, name, severity, precision, CWE, tags, help page, description).
+# The report writers read rule levels and precision from it when a SARIF
+# file does not carry its own rule descriptors, and link findings to the
+# help page (helpBaseUri + help) and CWE entries it lists.
+#
+# Usage:
+#   ./test/rules.sh               # Table: id, severity, precision, CWE, name
+#   ./test/rules.sh --json        # The registry as JSON
+#   ./test/rules.sh --sarif-rules # SARIF reportingDescriptor objects
+#   ./test/rules.sh --check       # Validate the registry against the queries
+#   ./test/rules.sh --profiles    # Table: profile, number of rules, description
+#   ./test/rules.sh --profile strict [--json|--sarif-rules]  # Only that profile's rules
+#   ./test/rules.sh --explain unchecked-cosmwasm-arithmetic [--format md]
+#
+# A profile (standard, strict, audit; see "profiles" in rules.json) selects
+# the rules whose severity, precision and tags are among the ones it lists;
+# a field it leaves out matches every rule. includeTags adds the rules with
+# one of its tags whatever their severity and precision. standard is the
+# high-precision security rules, strict adds medium precision, non-security
+# checks and the posture rules, and audit is every rule, so audit includes
+# strict and strict includes standard.
+#
+# --explain prints one rule for reading offline: its name and metadata, the
+# Description of its help page, the vulnerable example, the Recommendation
+# and its CWE links. The id may omit the cosmwasm/ prefix. Plain text by
+# default; --format md keeps the Markdown of the help page.
+#
+# --check fails when rules.json does not match rules.schema.json, when a
+# query under src/queries has no entry or an entry has no query (orphans),
+# when an entry differs from its query's metadata header, or when its help
+# page is missing or has an empty Description section. Adding a query means
+# adding its entry here.
+#
+# Requirements: jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+PROJECT_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+REGISTRY="$PROJECT_ROOT/src/registry/rules.json"
+SCHEMA="$PROJECT_ROOT/src/registry/rules.schema.json"
+
+MODE="list"
+PROFILE=""
+EXPLAIN_ID=""
+FORMAT="text"
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --list) MODE="list"; shift ;;
+    --json) MODE="json"; shift ;;
+    --sarif-rules) MODE="sarif"; shift ;;
+    --check) MODE="check"; shift ;;
+    --profiles) MODE="profiles"; shift ;;
+    --profile) PROFILE="$2"; shift 2 ;;
+    --explain) MODE="explain"; EXPLAIN_ID="$2"; shift 2 ;;
+    --format) FORMAT="$2"; shift 2 ;;
+    *) echo "usage: rules.sh [--list|--json|--sarif-rules|--check|--profiles] [--profile NAME] [--explain ID [--format text|md]]" >&2; exit 2 ;;
+  esac
+done
+
+if [ -n "$PROFILE" ] && ! jq -e --arg profile "$PROFILE" '.profiles | has($profile)' "$REGISTRY" >/dev/null; then
+  echo "rules.sh: unknown profile '$PROFILE' (known: $(jq -r '.profiles | keys_unsorted | join(", ")' "$REGISTRY"))" >&2
+  exit 2
+fi
+
+if [ "$FORMAT" != "text" ] && [ "$FORMAT" != "md" ]; then
+  echo "rules.sh: unknown format '$FORMAT' (known: text, md)" >&2
+  exit 2
+fi
+
+# Prints the registry, keeping only the rules of --profile when one is given
+registry() {
+  jq --arg profile "$PROFILE" '
+    if $profile == "" then . else
+      .profiles[$profile] as $p
+      | .rules |= map(. as $rule | select(
+          ($p.severity == null or any($p.severity[]; . == $rule.severity))
+          and ($p.precision == null or any($p.precision[]; . == $rule.precision))
+          and ($p.tags == null or any($p.tags[]; . as $tag | $rule.tags | any(. == $tag)))
+          or any($p.includeTags[]?; . as $tag | $rule.tags | any(. == $tag))
+        ))
+    end
+  ' "$REGISTRY"
+}
+
+# Prints the metadata header of query <file> as a registry entry. Wrapped
+# @description lines are joined with single spaces.
+query_metadata() {
+  local file="$1" rel="${1#"$PROJECT_ROOT"/}"
+  awk '
+    /^\/\*\*/ { in_header = 1; next }
+    in_header && /\*\// { exit }
+    in_header {
+      sub(/^[ \t]*\*[ \t]?/, "")
+      if (match($0, /^@[a-z.]+/)) {
+        key = substr($0, 2, RLENGTH - 1)
+        val = substr($0, RLENGTH + 1)
+      } else {
+        val = val " " $0
+      }
+      gsub(/^[ \t]+|[ \t]+$/, "", val)
+      gsub(/[ \t]+/, " ", val)
+      meta[key] = val
+    }
+    END { for (k in meta) printf "%s\t%s\n", k, meta[k] }
+  ' "$file" | jq -R -s --arg query "$rel" '
+    split("\n") | map(select(length > 0) | split("\t") | {key: .[0], value: .[1:] | join("\t")})
+    | from_entries as $m
+    | ($m.tags // "" | split(" ") | map(select(length > 0))) as $tags
+    | {
+        id: $m.id,
+        name: $m.name,
+        query: $query,
+        category: ($query | split("/")[2]),
+        severity: $m["problem.severity"],
+        precision: $m.precision,
+        cwe: [$tags[] | select(startswith("external/cwe/cwe-")) | "CWE-" + ltrimstr("external/cwe/cwe-")],
+        tags: [$tags[] | select(startswith("external/") | not)],
+        help: ("docs/query-help/" + ($m.id | ltrimstr("cosmwasm/")) + ".md"),
+        description: $m.description,
+        kind: $m.kind
+      }
+  '
+}
+
+# Prints the body of section <heading> (a whole line such as "## Recommendation"
+# or "### Vulnerable Code") of Markdown file <file>, up to the next heading of
+# the same or a higher level, without surrounding blank lines.
+help_section() {
+  local file="$1" heading="$2"
+  awk -v heading="$heading" '
+    BEGIN { level = index(heading, " ") - 1 }
+    /^```/ { fenced = !fenced }
+    !fenced && /^#+ / {
+      if (in_section && index($0, " ") - 1 <= level) exit
+      if ($0 == heading) { in_section = 1; next }
+    }
+    in_section { lines[++n] = $0 }
+    END {
+      first = 1; while (first <= n && lines[first] == "") first++
+      last = n; while (last >= first && lines[last] == "") last--
+      for (i = first; i <= last; i++) print lines[i]
+    }
+  ' "$file"
+}
+
+case "$MODE" in
+  explain)
+    rule=$(jq --arg id "cosmwasm/${EXPLAIN_ID#cosmwasm/}" '.rules[] | select(.id == $id)' "$REGISTRY")
+    if [ -z "$rule" ]; then
+      echo "rules.sh: unknown rule '$EXPLAIN_ID' (see rules.sh --list)" >&2
+      exit 2
+    fi
+    help="$PROJECT_ROOT/$(jq -r .help <<<"$rule")"
+    description=$(help_section "$help" "## Description")
+    example=$(help_section "$help" "### Vulnerable Code")
+    recommendation=$(help_section "$help" "## Recommendation")
+    cwes=$(jq -r '.cwe[] | "\(.) https://cwe.mitre.org/data/definitions/\(ltrimstr("CWE-")).html"' <<<"$rule")
+    if [ "$FORMAT" = "md" ]; then
+      jq -r '"# \(.name)\n\n`\(.id)`: \(.severity), \(.precision) precision"' <<<"$rule"
+      printf '\n## Description\n%s\n\n## Vulnerable Code\n%s\n\n## Recommendation\n%s\n' \
+        "$description" "$example" "$recommendation"
+      if [ -n "$cwes" ]; then
+        printf '\n## References\n'
+        while read -r cwe url; do printf -- '- [%s](%s)\n' "$cwe" "$url"; done <<<"$cwes"
+      fi
+    else
+      jq -r '"\(.name)\n\(.id): \(.severity), \(.precision) precision"' <<<"$rule"
+      printf '\nDESCRIPTION\n%s\n\nVULNERABLE CODE\n%s\n\nRECOMMENDATION\n%s\n' \
+        "$(sed 's/^/  /; s/^  $//' <<<"$description")" \
+        "$(grep -v '^```' <<<"$example" | sed 's/^/    /; s/^    $//')" \
+        "$(sed 's/^/  /; s/^  $//' <<<"$recommendation")"
+      if [ -n "$cwes" ]; then
+        printf '\nCWE\n'
+        while read -r cwe url; do printf '  %s  %s\n' "$cwe" "$url"; done <<<"$cwes"
+      fi
+    fi
+    ;;
+  json)
+    registry
+    ;;
+  list)
+    registry | jq -r '
+      .rules[]
+      | [.id, .severity, .precision, (if .cwe == [] then "-" else .cwe | join(",") end), .name]
+      | @tsv
+    '
+    ;;
+  profiles)
+    for profile in $(jq -r '.profiles | keys_unsorted[]' "$REGISTRY"); do
+      PROFILE="$profile" registry | jq -r --arg profile "$profile" '[$profile, (.rules | length), .profiles[$profile].description] | @tsv'
+    done
+    ;;
+  sarif)
+    # Same shape as the rules CodeQL writes to tool.driver.rules
+    registry | jq '.helpBaseUri as $base | [
+      .rules[] | {
+        id,
+        name: (.query | split("/")[-1] | rtrimstr(".ql")),
+        shortDescription: {text: .name},
+        fullDescription: {text: .description},
+        helpUri: ($base + .help),
+        defaultConfiguration: {level: (if .severity == "recommendation" then "note" else .severity end)},
+        properties: {
+          tags: (.tags + [.cwe[] | "external/cwe/" + ascii_downcase]),
+          precision,
+          "problem.severity": .severity
+        }
+      }
+    ]'
+    ;;
+  check)
+    errors=$(jq -r --slurpfile schema "$SCHEMA" '
+      # Subset of JSON Schema used by rules.schema.json
+      def schema_errors($s; $path):
+        if ($s.type // null) != null and type != $s.type then
+          "\($path): expected \($s.type), got \(type)"
+        else
+          (if $s.enum and (. as $v | any($s.enum[]; . == $v) | not) then
+             "\($path): \(tojson) is not one of \($s.enum | join(", "))" else empty end),
+          (if $s.pattern and (test($s.pattern) | not) then
+             "\($path): \(tojson) does not match \($s.pattern)" else empty end),
+          (if $s.minLength and length < $s.minLength then "\($path): empty string" else empty end),
+          (if type == "array" then
+             (if $s.minItems and length < $s.minItems then "\($path): fewer than \($s.minItems) items" else empty end),
+             (if $s.items then
+                to_entries[] | .key as $i | .value | schema_errors($s.items; "\($path)[\($i)]")
+              else empty end)
+           else empty end),
+          (if type == "object" then
+             (($s.required // [])[] as $key | if has($key) then empty else "\($path): missing \($key)" end),
+             (to_entries[] | .key as $key
+              | if ($s.properties // {})[$key] then .value | schema_errors($s.properties[$key]; "\($path).\($key)")
+                elif $s.additionalProperties == false then "\($path): unknown property \($key)"
+                elif ($s.additionalProperties | type) == "object" then
+                  .value | schema_errors($s.additionalProperties; "\($path).\($key)")
+                else empty end)
+           else empty end)
+        end;
+      schema_errors($schema[0]; "rules.json"),
+      (.rules | group_by(.id)[] | select(length > 1) | "rules.json: duplicate id \(.[0].id)")
+    ' "$REGISTRY")
+
+    queries=$(find "$PROJECT_ROOT/src/queries" -name '*.ql' | sort | while read -r ql; do
+      query_metadata "$ql"
+    done | jq -s 'map(select(.kind == "problem") | del(.kind))')
+
+    errors+=$'\n'$(jq -r --argjson queries "$queries" '
+      (.rules | map({key: .id, value: .}) | from_entries) as $registered
+      | ($queries | map({key: .id, value: .}) | from_entries) as $implemented
+      | ($queries[] | select($registered[.id] == null) | "\(.query): \(.id) has no registry entry"),
+        (.rules[] | select($implemented[.id] == null) | "rules.json: \(.id) has no query"),
+        (.rules[] | . as $entry | $implemented[.id] // empty
+         | to_entries[] | select($entry[.key] != .value)
+         | "rules.json: \($entry.id) \(.key) is \($entry[.key] | tojson), query says \(.value | tojson)")
+    ' "$REGISTRY")
+
+    while read -r help; do
+      if [ ! -f "$PROJECT_ROOT/$help" ]; then
+        errors+=$'\n'"rules.json: help page $help does not exist"
+      elif ! awk '/^## / { in_section = ($0 == "## Description"); next } in_section && NF { found = 1 }
+                  END { exit !found }' "$PROJECT_ROOT/$help"; then
+        errors+=$'\n'"rules.json: help page $help has no Description text"
+      fi
+    done < <(jq -r '.rules[].help' "$REGISTRY")
+
+    errors=$(printf '%s\n' "$errors" | grep . || true)
+    if [ -n "$errors" ]; then
+      echo "$errors" >&2
+      exit 1
+    fi
+    echo "$(jq '.rules | length' "$REGISTRY") rules registered, all match their queries"
+    ;;
+esac
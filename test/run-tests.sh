@@ -1,6 +1,7 @@
 #!/bin/bash
 # CosmWasm CodeQL Query Test Runner
-# Creates databases from fixtures and verifies query results.
+# Creates databases from fixtures and verifies query results and SARIF output.
+# Requirements: codeql CLI, jq, xmllint or python3
 set -eo pipefail
 
 SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
@@ -39,9 +40,10 @@ run_query() {
 echo "=== CosmWasm CodeQL Test Runner ==="
 echo ""
 
-# Step 1: Build databases
+# Step 1: Build databases (one per fixture crate)
 mkdir -p "$DB_DIR"
-for fixture in vulnerable-contract safe-contract; do
+for fixture_dir in "$FIXTURES_DIR"/*/; do
+  fixture=$(basename "$fixture_dir")
   db_path="$DB_DIR/${fixture}-db"
   if [ -d "$db_path" ] && [ "$REBUILD" != "--rebuild" ]; then
     echo "Using cached database: $db_path"
@@ -54,20 +56,167 @@ for fixture in vulnerable-contract safe-contract; do
       2>&1 | tail -1
   fi
 done
+
+# Cargo.toml is not in the databases: record which crates (fixtures and the
+# generated copies below) build with overflow-checks as a data extension.
+write_overflow_checks() {
+  local roots=("$FIXTURES_DIR")
+  [ -d "$DB_DIR/shifted-contract" ] && roots+=("$DB_DIR/shifted-contract")
+  [ -d "$DB_DIR/workspace" ] && roots+=("$DB_DIR/workspace")
+  bash "$SCRIPT_DIR/overflow-checks.sh" "${roots[@]}" 2>/dev/null
+}
+write_overflow_checks
 echo ""
 
 # Step 2: Define tests as "query_path:expected_vuln_count"
 TESTS=(
-  "src/queries/access-control/MissingExecuteAuthorization.ql:2"
+  "src/queries/access-control/MissingExecuteAuthorization.ql:9"
   "src/queries/access-control/MissingMigrateAuthorization.ql:1"
-  "src/queries/access-control/UnprotectedExecuteDispatch.ql:2"
-  "src/queries/data-safety/UncheckedCosmwasmArithmetic.ql:1"
-  "src/queries/data-safety/UncheckedStorageUnwrap.ql:1"
-  "src/queries/data-safety/MissingAddressValidation.ql:1"
-  "src/queries/data-safety/StorageKeyCollision.ql:1"
+  "src/queries/access-control/UnprotectedExecuteDispatch.ql:8"
+  "src/queries/data-safety/UncheckedCosmwasmArithmetic.ql:6"
+  "src/queries/data-safety/UncheckedStorageUnwrap.ql:2"
+  "src/queries/data-safety/MissingAddressValidation.ql:2"
+  "src/queries/data-safety/StorageKeyCollision.ql:2"
   "src/queries/cross-contract/IbcCeiViolation.ql:1"
-  "src/queries/cross-contract/SubmsgWithoutReplyHandler.ql:1"
+  "src/queries/cross-contract/SubmsgWithoutReplyHandler.ql:2"
   "src/queries/cross-contract/ReplyHandlerIgnoringErrors.ql:0"
+  "src/queries/cross-contract/ReentrancyViaReply.ql:0"
+  "src/queries/data-safety/MissingMigrateVersionCheck.ql:1"
+  "src/queries/denial-of-service/UnboundedMapIteration.ql:1"
+  "src/queries/determinism/FloatUsage.ql:2"
+  "src/queries/data-safety/UnvalidatedFunds.ql:3"
+  "src/queries/data-safety/LossyIntegerCast.ql:1"
+  "src/queries/determinism/NondeterministicIteration.ql:1"
+  "src/queries/determinism/BlockDataAsRandomness.ql:1"
+  "src/queries/cross-contract/SendToUnvalidatedAddress.ql:3"
+  "src/queries/data-safety/DivisionByZero.ql:1"
+  "src/queries/data-safety/MissingZeroAmountCheck.ql:12"
+  "src/queries/access-control/NoPauseMechanism.ql:0"
+  "src/queries/denial-of-service/ExplicitPanic.ql:1"
+  "src/queries/denial-of-service/PanickingIndex.ql:2"
+  "src/queries/cross-contract/UntrustedQueryResult.ql:1"
+  "src/queries/cross-contract/MissingIbcHandler.ql:0"
+  "src/queries/data-safety/MissingCw2Init.ql:1"
+  "src/queries/data-safety/HardcodedAddress.ql:0"
+  "src/queries/data-safety/HardcodedCoin.ql:1"
+  "src/queries/data-safety/UnexpectedFunds.ql:27"
+  "src/queries/data-safety/MissingStatusGate.ql:0"
+  "src/queries/access-control/SingleStepAdminTransfer.ql:1"
+  "src/queries/cross-contract/IbcHandshakeUnvalidated.ql:0"
+  "src/queries/cross-contract/IbcRefundTrust.ql:0"
+  "src/queries/denial-of-service/UnboundedInput.ql:2"
+  "src/queries/data-safety/MutationInQuery.ql:0"
+  "src/queries/cross-contract/ReplyIdCollision.ql:0"
+  "src/queries/data-safety/DecimalConversionHazard.ql:3"
+  "src/queries/cross-contract/InstantiateNoAdmin.ql:2"
+  "src/queries/data-safety/SaturatingArithmetic.ql:0"
+  "src/queries/data-safety/UncheckedDepositDenom.ql:2"
+  "src/queries/cross-contract/UncheckedReplyData.ql:0"
+  "src/queries/access-control/UnusedAdminField.ql:1"
+  "src/queries/denial-of-service/UnboundedStorageLoop.ql:0"
+  "src/queries/access-control/MissingSelfCallGuard.ql:3"
+  "src/queries/access-control/Cw20ReceiveUnvalidated.ql:2"
+  "src/queries/data-safety/TimestampUnitMismatch.ql:0"
+  "src/queries/cross-contract/DroppedMessage.ql:0"
+  "src/queries/access-control/SudoSharedHelper.ql:0"
+  "src/queries/denial-of-service/UnboundedPagination.ql:2"
+  "src/queries/data-safety/MissingEntryPointAttr.ql:0"
+  "src/queries/cross-contract/InvalidCoinList.ql:2"
+  "src/queries/data-safety/IgnoredResult.ql:3"
+  "src/queries/data-safety/LoopAccumulatorOverflow.ql:2"
+  "src/queries/data-safety/SelfTransferHazard.ql:1"
+  "src/queries/denial-of-service/PanickingUpdateClosure.ql:3"
+  "src/queries/access-control/BlindOverwrite.ql:2"
+  "src/queries/denial-of-service/QueryInLoop.ql:2"
+  "src/queries/data-safety/MigrateMsgNoVersion.ql:0"
+  "src/queries/cross-contract/ReplyCatchAllOk.ql:0"
+  "src/queries/data-safety/SuspiciousAmountLiteral.ql:2"
+  "src/queries/data-safety/RawStorageAccess.ql:4"
+  "src/queries/data-safety/StringAddressInState.ql:2"
+  "src/queries/cross-contract/CrossMapConsistency.ql:1"
+  "src/queries/data-safety/IncompleteInit.ql:5"
+  "src/queries/data-safety/UnnormalizedMapKey.ql:2"
+  "src/queries/data-safety/GenericErrorOveruse.ql:1"
+  "src/queries/cross-contract/IbcAckMismatch.ql:0"
+  "src/queries/data-safety/ExpiryBoundary.ql:2"
+  "src/queries/data-safety/InconsistentPayability.ql:0"
+  "src/queries/data-safety/UnboundedAmountParse.ql:2"
+  "src/queries/data-safety/QueryResponseUntyped.ql:3"
+  "src/queries/data-safety/SwallowedError.ql:3"
+  "src/queries/cross-contract/UntrustedQueryAddress.ql:2"
+  "src/queries/access-control/SenderTypeAssumption.ql:2"
+  "src/queries/denial-of-service/UncheckedPaginationCursor.ql:3"
+  "src/queries/data-safety/ErrorTypeBypass.ql:2"
+  "src/queries/denial-of-service/UnboundedSelfRecursion.ql:3"
+  "src/queries/denial-of-service/BinarySerializationUnwrap.ql:2"
+)
+
+# Scenario fixtures that need their own contract (e.g. a second reply()
+# entry point). Format: "fixture:query_path:expected_count"
+FIXTURE_TESTS=(
+  "reply-reentrancy-contract:src/queries/cross-contract/ReentrancyViaReply.ql:1"
+  "reply-unhandled-id-contract:src/queries/cross-contract/SubmsgWithoutReplyHandler.ql:1"
+  "reply-ignores-error-contract:src/queries/cross-contract/ReplyHandlerIgnoringErrors.ql:2"
+  "reply-unhandled-id-contract:src/queries/cross-contract/ReplyHandlerIgnoringErrors.ql:0"
+  "suppression-contract:src/queries/AlertSuppression.ql:3"
+  "overflow-checks-contract:src/queries/data-safety/UncheckedCosmwasmArithmetic.ql:1"
+  "no-pause-contract:src/queries/access-control/NoPauseMechanism.ql:1"
+  "oracle-contract:src/queries/cross-contract/UntrustedQueryResult.ql:1"
+  "partial-ibc-contract:src/queries/cross-contract/MissingIbcHandler.ql:2"
+  "hardcoded-address-contract:src/queries/data-safety/HardcodedAddress.ql:1"
+  "hardcoded-address-contract:src/queries/data-safety/HardcodedCoin.ql:0"
+  "nonpayable-contract:src/queries/data-safety/UnexpectedFunds.ql:1"
+  "status-gate-contract:src/queries/data-safety/MissingStatusGate.ql:1"
+  "two-step-admin-contract:src/queries/access-control/SingleStepAdminTransfer.ql:0"
+  "ibc-handshake-contract:src/queries/cross-contract/IbcHandshakeUnvalidated.ql:2"
+  "ibc-handshake-safe-contract:src/queries/cross-contract/IbcHandshakeUnvalidated.ql:0"
+  "ibc-refund-contract:src/queries/cross-contract/IbcRefundTrust.ql:2"
+  "ibc-refund-safe-contract:src/queries/cross-contract/IbcRefundTrust.ql:0"
+  "batch-mint-contract:src/queries/denial-of-service/UnboundedInput.ql:2"
+  "query-mutation-contract:src/queries/data-safety/MutationInQuery.ql:1"
+  # Posture query: both standard fixtures return bare responses from state-changing handlers
+  "vulnerable-contract:src/queries/data-safety/NoEventsEmitted.ql:2"
+  "safe-contract:src/queries/data-safety/NoEventsEmitted.ql:4"
+  "events-contract:src/queries/data-safety/NoEventsEmitted.ql:0"
+  "reply-id-collision-contract:src/queries/cross-contract/ReplyIdCollision.ql:1"
+  "reply-id-safe-contract:src/queries/cross-contract/ReplyIdCollision.ql:0"
+  "saturating-contract:src/queries/data-safety/SaturatingArithmetic.ql:2"
+  "reply-data-contract:src/queries/cross-contract/UncheckedReplyData.ql:2"
+  "reply-data-safe-contract:src/queries/cross-contract/UncheckedReplyData.ql:0"
+  "vulnerable-contract:src/queries/access-control/UnusedAdminField.ql:1"
+  "admin-enforced-contract:src/queries/access-control/UnusedAdminField.ql:0"
+  "storage-loop-contract:src/queries/denial-of-service/UnboundedStorageLoop.ql:2"
+  "batch-mint-contract:src/queries/denial-of-service/UnboundedStorageLoop.ql:1"
+  "timelock-contract:src/queries/data-safety/TimestampUnitMismatch.ql:2"
+  "dropped-message-contract:src/queries/cross-contract/DroppedMessage.ql:2"
+  "sudo-contract:src/queries/access-control/SudoSharedHelper.ql:1"
+  "sudo-safe-contract:src/queries/access-control/SudoSharedHelper.ql:0"
+  "missing-entry-point-contract:src/queries/data-safety/MissingEntryPointAttr.ql:1"
+  "entry-point-safe-contract:src/queries/data-safety/MissingEntryPointAttr.ql:0"
+  "storage-loop-contract:src/queries/data-safety/LoopAccumulatorOverflow.ql:0"
+  "status-gate-contract:src/queries/access-control/BlindOverwrite.ql:0"
+  "migrate-version-contract:src/queries/data-safety/MigrateMsgNoVersion.ql:1"
+  "migrate-version-safe-contract:src/queries/data-safety/MigrateMsgNoVersion.ql:0"
+  "reply-catch-all-contract:src/queries/cross-contract/ReplyCatchAllOk.ql:1"
+  "reply-catch-all-safe-contract:src/queries/cross-contract/ReplyCatchAllOk.ql:0"
+  "reply-id-safe-contract:src/queries/cross-contract/ReplyCatchAllOk.ql:0"
+  "admin-enforced-contract:src/queries/data-safety/StringAddressInState.ql:0"
+  "reply-reentrancy-contract:src/queries/cross-contract/CrossMapConsistency.ql:0"
+  "ibc-ack-contract:src/queries/cross-contract/IbcAckMismatch.ql:2"
+  "ibc-ack-safe-contract:src/queries/cross-contract/IbcAckMismatch.ql:0"
+  "partial-ibc-contract:src/queries/cross-contract/IbcAckMismatch.ql:2"
+  "timelock-contract:src/queries/data-safety/ExpiryBoundary.ql:2"
+  "oracle-contract:src/queries/data-safety/ExpiryBoundary.ql:0"
+  "payability-contract:src/queries/data-safety/InconsistentPayability.ql:1"
+  "payability-safe-contract:src/queries/data-safety/InconsistentPayability.ql:0"
+  "oracle-contract:src/queries/cross-contract/UntrustedQueryAddress.ql:0"
+  "ibc-ack-contract:src/queries/denial-of-service/BinarySerializationUnwrap.ql:0"
+  "funds-order-contract:src/queries/data-safety/UnvalidatedFunds.ql:1"
+  "funds-order-safe-contract:src/queries/data-safety/UnvalidatedFunds.ql:0"
+  "reply-map-key-contract:src/queries/cross-contract/ReentrancyViaReply.ql:1"
+  "reply-map-key-safe-contract:src/queries/cross-contract/ReentrancyViaReply.ql:0"
+  "range-start-bound-contract:src/queries/denial-of-service/UnboundedMapIteration.ql:1"
+  "range-start-bound-safe-contract:src/queries/denial-of-service/UnboundedMapIteration.ql:0"
 )
 
 echo "--- Vulnerable Contract Tests ---"
@@ -106,6 +255,813 @@ for test_spec in "${TESTS[@]}"; do
   fi
 done
 
+echo ""
+echo "--- Scenario Fixture Tests ---"
+for test_spec in "${FIXTURE_TESTS[@]}"; do
+  fixture="${test_spec%%:*}"
+  rest="${test_spec#*:}"
+  query="${rest%%:*}"
+  expected="${rest##*:}"
+  name=$(basename "$query" .ql)
+
+  output=$(run_query "$DB_DIR/${fixture}-db" "$query")
+  actual=$(count_results "$output")
+
+  if [ "$actual" -eq "$expected" ]; then
+    echo "  $(green PASS) $fixture/$name: $actual results (expected $expected)"
+    PASS=$((PASS + 1))
+  else
+    echo "  $(red FAIL) $fixture/$name: $actual results (expected $expected)"
+    FAIL=$((FAIL + 1))
+  fi
+done
+
+# Of the three execute variants, only the one without a funds guard is reported
+output=$(run_query "$DB_DIR/payability-contract-db" "src/queries/data-safety/InconsistentPayability.ql")
+if echo "$output" | grep -q "'ExecuteMsg::Withdraw'" && ! echo "$output" | grep -q "'ExecuteMsg::\(Deposit\|SetName\)'"; then
+  echo "  $(green PASS) payability-contract/InconsistentPayability: only Withdraw is reported"
+  PASS=$((PASS + 1))
+else
+  echo "  $(red FAIL) payability-contract/InconsistentPayability: only Withdraw is reported"
+  FAIL=$((FAIL + 1))
+fi
+
+echo ""
+echo "--- Expected Findings Tests ---"
+# Fixtures with an expected.json, analyzed with every registry query: the
+# findings must be exactly the listed query ids and lines.
+registry_queries=()
+while IFS= read -r query; do
+  registry_queries+=("$PROJECT_ROOT/$query")
+done < <(jq -r '.rules[].query' "$PROJECT_ROOT/src/registry/rules.json")
+for expected_file in "$FIXTURES_DIR"/*/expected.json; do
+  fixture=$(basename "$(dirname "$expected_file")")
+  codeql database analyze "$DB_DIR/${fixture}-db" "${registry_queries[@]}" \
+    --additional-packs="$PROJECT_ROOT" \
+    --format=sarifv2.1.0 \
+    --output="$DB_DIR/${fixture}.expected.sarif" \
+    --rerun >/dev/null 2>&1
+  if diff_output=$(bash "$SCRIPT_DIR/expected-findings.sh" "$FIXTURES_DIR/$fixture" "$DB_DIR/${fixture}.expected.sarif"); then
+    echo "  $(green PASS) $fixture: findings match expected.json"
+    PASS=$((PASS + 1))
+  else
+    echo "  $(red FAIL) $fixture: findings differ from expected.json"
+    printf '%s\n' "$diff_output" | sed 's/^/      /'
+    FAIL=$((FAIL + 1))
+  fi
+done
+
+echo ""
+echo "--- Call Graph Tests ---"
+# Rows are: function | reachable or unreachable | comma-separated direct callers
+check_call_graph() {
+  local desc="$1" pattern="$2"
+  if printf '%s\n' "$graph" | grep -qE "$pattern"; then
+    echo "  $(green PASS) $fixture: $desc"
+    PASS=$((PASS + 1))
+  else
+    echo "  $(red FAIL) $fixture: $desc"
+    FAIL=$((FAIL + 1))
+  fi
+}
+
+for fixture in vulnerable-contract safe-contract; do
+  graph=$(run_query "$DB_DIR/${fixture}-db" "test/library-tests/CallGraph.ql")
+  check_call_graph "execute_mint is reachable" '^\| *execute_mint +\| *reachable +\|'
+  check_call_graph "execute_mint is called by execute" '^\| *execute_mint +\|[^|]*\| *execute +\|'
+  check_call_graph "compute_fee is reachable through execute_mint" '^\| *compute_fee +\| *reachable +\| *execute_mint +\|'
+  check_call_graph "unused legacy_config_key is unreachable" '^\| *legacy_config_key +\| *unreachable +\|'
+done
+
+echo ""
+echo "--- Entry Point Inventory Tests ---"
+# Rows are: handler <TAB> present or missing <TAB> file:line
+check_entry_points() {
+  local desc="$1" pattern="$2"
+  if printf '%s\n' "$inventory" | grep -qE "$pattern"; then
+    echo "  $(green PASS) $fixture: $desc"
+    PASS=$((PASS + 1))
+  else
+    echo "  $(red FAIL) $fixture: $desc"
+    FAIL=$((FAIL + 1))
+  fi
+}
+
+fixture=partial-ibc-contract
+inventory=$(bash "$SCRIPT_DIR/entry-points.sh" "$FIXTURES_DIR/$fixture")
+check_entry_points "ibc_packet_receive is present" $'^ibc_packet_receive\tpresent\tsrc/ibc.rs:55$'
+check_entry_points "ibc_packet_ack is missing" $'^ibc_packet_ack\tmissing\t$'
+check_entry_points "ibc_packet_timeout is missing" $'^ibc_packet_timeout\tmissing\t$'
+check_entry_points "instantiate is missing" $'^instantiate\tmissing\t$'
+# The query's rows name each missing companion
+inventory=$(run_query "$DB_DIR/${fixture}-db" "src/queries/cross-contract/MissingIbcHandler.ql")
+check_entry_points "warns that ibc_packet_ack is missing" "'ibc_packet_receive' but no 'ibc_packet_ack'"
+check_entry_points "warns that ibc_packet_timeout is missing" "'ibc_packet_receive' but no 'ibc_packet_timeout'"
+
+fixture=safe-contract
+inventory=$(bash "$SCRIPT_DIR/entry-points.sh" "$FIXTURES_DIR/$fixture")
+check_entry_points "reply is present" $'^reply\tpresent\tsrc/contract.rs:[0-9]+$'
+if printf '%s\n' "$inventory" | grep -q '^ibc_'; then
+  echo "  $(red FAIL) $fixture: IBC handlers are not listed without IBC entry points"
+  FAIL=$((FAIL + 1))
+else
+  echo "  $(green PASS) $fixture: IBC handlers are not listed without IBC entry points"
+  PASS=$((PASS + 1))
+fi
+
+echo ""
+echo "--- SARIF Output Tests ---"
+# Analyze the vulnerable fixture with every query in TESTS and check the
+# SARIF 2.1.0 fields GitHub code scanning relies on (upload-sarif).
+SARIF_FILE="$DB_DIR/vulnerable-contract.sarif"
+sarif_queries=()
+for test_spec in "${TESTS[@]}"; do
+  sarif_queries+=("$PROJECT_ROOT/${test_spec%%:*}")
+done
+codeql database analyze "$DB_DIR/vulnerable-contract-db" "${sarif_queries[@]}" \
+  --additional-packs="$PROJECT_ROOT" \
+  --format=sarifv2.1.0 \
+  --output="$SARIF_FILE" \
+  --rerun >/dev/null 2>&1
+
+# Rules may be reported on the driver or on the pack extension
+SARIF_RULES='[.runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?]'
+
+check_sarif() {
+  local description="$1" filter="$2"
+  if [ "$(jq "$filter" "$SARIF_FILE" 2>/dev/null)" = "true" ]; then
+    echo "  $(green PASS) $description"
+    PASS=$((PASS + 1))
+  else
+    echo "  $(red FAIL) $description"
+    FAIL=$((FAIL + 1))
+  fi
+}
+
+check_sarif "SARIF version is 2.1.0" '.version == "2.1.0"'
+check_sarif "every result has a cosmwasm/ ruleId" \
+  '[.runs[].results[] | .ruleId // "" | startswith("cosmwasm/")] | all'
+check_sarif "every result has uri, startLine and startColumn" \
+  '[.runs[].results[].locations[0].physicalLocation
+    | (.artifactLocation.uri != null) and (.region.startLine != null) and (.region.startColumn != null)] | all'
+check_sarif "every rule has a shortDescription" \
+  "$SARIF_RULES | map(.shortDescription.text // \"\" | length > 0) | all"
+
+# The whole run against test/sarif/expected.json: every rule's name, level
+# and precision, and every result's rule and location, with the source paths
+# made relative to the fixture. Update it with the expected.json of the
+# fixture when a TESTS query or its findings change.
+SARIF_NORMALIZE='{
+  version,
+  rules: ([.runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?]
+    | map({id, name: .shortDescription.text, level: .defaultConfiguration.level, precision: .properties.precision})
+    | unique_by(.id)),
+  results: ([.runs[].results[]
+    | select((.suppressions // []) | length == 0)
+    | .locations[0].physicalLocation as $loc
+    | {ruleId,
+       uri: ($loc.artifactLocation.uri | sub("^file://"; "") | sub("^.*/vulnerable-contract/"; "")),
+       startLine: $loc.region.startLine}]
+    | sort_by(.uri, .startLine, .ruleId))
+}'
+if sarif_diff=$(diff <(jq -S . "$SCRIPT_DIR/sarif/expected.json") <(jq -S "$SARIF_NORMALIZE" "$SARIF_FILE")); then
+  echo "  $(green PASS) normalized SARIF matches test/sarif/expected.json"
+  PASS=$((PASS + 1))
+else
+  echo "  $(red FAIL) normalized SARIF matches test/sarif/expected.json"
+  printf '%s\n' "$sarif_diff" | head -10 | sed 's/^/    /'
+  FAIL=$((FAIL + 1))
+fi
+
+for test_spec in "${TESTS[@]}"; do
+  query="${test_spec%%:*}"
+  expected="${test_spec##*:}"
+  name=$(basename "$query" .ql)
+  rule_id=$(sed -n 's/^ \* @id //p' "$PROJECT_ROOT/$query")
+  severity=$(sed -n 's/^ \* @problem.severity //p' "$PROJECT_ROOT/$query")
+  level="$severity"
+  [ "$severity" = "recommendation" ] && level="note"
+
+  if [ "$expected" -gt 0 ]; then
+    check_sarif "$name: rule $rule_id with level $level" \
+      "$SARIF_RULES | map(select(.id == \"$rule_id\") | .defaultConfiguration.level == \"$level\") | (length > 0 and all)"
+  fi
+  check_sarif "$name: $expected SARIF results" \
+    "[.runs[].results[] | select(.ruleId == \"$rule_id\")] | length == $expected"
+done
+
+echo ""
+echo "--- JUnit Output Tests ---"
+# Golden file from a fixed SARIF input, then the real vulnerable and safe runs.
+check_junit() {
+  local description="$1"
+  shift
+  if "$@" >/dev/null 2>&1; then
+    echo "  $(green PASS) $description"
+    PASS=$((PASS + 1))
+  else
+    echo "  $(red FAIL) $description"
+    FAIL=$((FAIL + 1))
+  fi
+}
+
+xml_well_formed() {
+  if command -v xmllint >/dev/null 2>&1; then
+    xmllint --noout "$1" 2>/dev/null
+  else
+    python3 -c 'import sys, xml.dom.minidom; xml.dom.minidom.parse(sys.argv[1])' "$1" 2>/dev/null
+  fi
+}
+
+JUNIT_DIR="$DB_DIR/junit"
+mkdir -p "$JUNIT_DIR"
+bash "$SCRIPT_DIR/sarif-to-junit.sh" "$SCRIPT_DIR/junit/results.sarif" --out "$JUNIT_DIR/golden.xml"
+check_junit "output matches test/junit/expected.xml" diff -q "$SCRIPT_DIR/junit/expected.xml" "$JUNIT_DIR/golden.xml"
+check_junit "golden output is well-formed XML" xml_well_formed "$JUNIT_DIR/golden.xml"
+
+bash "$SCRIPT_DIR/sarif-to-junit.sh" "$SARIF_FILE" --out "$JUNIT_DIR/vulnerable.xml"
+check_junit "vulnerable-contract report is well-formed XML" xml_well_formed "$JUNIT_DIR/vulnerable.xml"
+check_junit "vulnerable-contract report has failures" grep -q '<failure ' "$JUNIT_DIR/vulnerable.xml"
+bash "$SCRIPT_DIR/sarif-to-junit.sh" "$SARIF_FILE" --threshold error --out "$JUNIT_DIR/vulnerable-errors.xml"
+check_junit "--threshold error passes warning-level findings" \
+  bash -c '! grep -q "<failure type=\"warning\"" "$1"' _ "$JUNIT_DIR/vulnerable-errors.xml"
+
+codeql database analyze "$DB_DIR/safe-contract-db" "${sarif_queries[@]}" \
+  --additional-packs="$PROJECT_ROOT" \
+  --format=sarifv2.1.0 \
+  --output="$DB_DIR/safe-contract.sarif" \
+  --rerun >/dev/null 2>&1
+bash "$SCRIPT_DIR/sarif-to-junit.sh" "$DB_DIR/safe-contract.sarif" --out "$JUNIT_DIR/safe.xml"
+check_junit "safe-contract report has zero failures" \
+  bash -c 'grep -q "<testsuites [^>]*failures=\"0\"" "$1" && ! grep -q "<failure " "$1"' _ "$JUNIT_DIR/safe.xml"
+
+echo ""
+echo "--- Severity Gate Tests ---"
+# Warning-only run: the golden SARIF input reduced to its warning-level rule.
+WARNING_SARIF="$JUNIT_DIR/warning-only.sarif"
+jq 'del(.runs[].results[] | select(.ruleId != "cosmwasm/unchecked-cosmwasm-arithmetic"))' \
+  "$SCRIPT_DIR/junit/results.sarif" > "$WARNING_SARIF"
+check_junit "warning-only run exits 0 under --fail-on error" \
+  bash "$SCRIPT_DIR/fail-on.sh" "$WARNING_SARIF" --fail-on error
+check_junit "warning-only run exits nonzero under --fail-on warning" \
+  bash -c '! bash "$1" "$2" --fail-on warning' _ "$SCRIPT_DIR/fail-on.sh" "$WARNING_SARIF"
+check_junit "safe-contract exits 0 under --fail-on note" \
+  bash "$SCRIPT_DIR/fail-on.sh" "$DB_DIR/safe-contract.sarif" --fail-on note
+check_junit "an unknown --fail-on severity is a usage error" \
+  bash -c 'bash "$1" "$2" --fail-on critical 2>/dev/null; [ $? -eq 2 ]' _ "$SCRIPT_DIR/fail-on.sh" "$WARNING_SARIF"
+
+echo ""
+echo "--- Markdown Report Tests ---"
+# Golden report from a fixed SARIF input over the vulnerable fixture sources.
+MARKDOWN_DIR="$DB_DIR/markdown"
+mkdir -p "$MARKDOWN_DIR/one-line/src"
+bash "$SCRIPT_DIR/sarif-to-markdown.sh" "$FIXTURES_DIR/vulnerable-contract" \
+  "$SCRIPT_DIR/markdown/results.sarif" --out "$MARKDOWN_DIR/golden.md"
+check_junit "output matches test/markdown/expected.md" \
+  diff -q "$SCRIPT_DIR/markdown/expected.md" "$MARKDOWN_DIR/golden.md"
+
+# Finding on the only line of a file without a trailing newline: the excerpt
+# is that line alone
+printf 'pub const FEE_BPS: u64 = 30;' > "$MARKDOWN_DIR/one-line/src/fees.rs"
+jq '.runs[].results |= map(select(.ruleId == "cosmwasm/float-usage")
+      | .locations[0].physicalLocation.region.startLine = 1)' \
+  "$SCRIPT_DIR/markdown/results.sarif" > "$MARKDOWN_DIR/one-line.sarif"
+bash "$SCRIPT_DIR/sarif-to-markdown.sh" "$MARKDOWN_DIR/one-line" "$MARKDOWN_DIR/one-line.sarif" \
+  --out "$MARKDOWN_DIR/one-line.md"
+check_junit "excerpt of a file's first and last line" \
+  bash -c '[ "$(grep -c "^[> ] *[0-9]* | " "$1")" -eq 1 ] && grep -qx ">    1 | pub const FEE_BPS: u64 = 30;" "$1"' \
+  _ "$MARKDOWN_DIR/one-line.md"
+check_junit "no excerpt for a line past the end of the file" \
+  bash -c '[ -z "$(bash "$1" "$2" 2)" ]' _ "$SCRIPT_DIR/excerpt-range.sh" "$MARKDOWN_DIR/one-line/src/fees.rs"
+
+bash "$SCRIPT_DIR/sarif-to-markdown.sh" "$FIXTURES_DIR/vulnerable-contract" \
+  "$DB_DIR/vulnerable-contract.sarif" --out "$MARKDOWN_DIR/vulnerable.md"
+check_junit "vulnerable-contract report has a section per rule with findings" \
+  bash -c '[ "$(grep -c "^### " "$1")" -eq "$(jq "[.runs[].results[].ruleId] | unique | length" "$2")" ]' \
+  _ "$MARKDOWN_DIR/vulnerable.md" "$DB_DIR/vulnerable-contract.sarif"
+
+echo ""
+echo "--- HTML Report Tests ---"
+# Same fixed SARIF input as the Markdown golden: one table row per
+# unsuppressed result.
+HTML_DIR="$DB_DIR/html"
+mkdir -p "$HTML_DIR/generics/src"
+bash "$SCRIPT_DIR/sarif-to-html.sh" "$FIXTURES_DIR/vulnerable-contract" \
+  "$SCRIPT_DIR/markdown/results.sarif" --out "$HTML_DIR/report.html"
+check_junit "one row per unsuppressed result" \
+  bash -c '[ "$(grep -c "^<tr class=.finding" "$1")" -eq "$(jq "[.runs[].results[] | select((.suppressions // []) | length == 0)] | length" "$2")" ]' \
+  _ "$HTML_DIR/report.html" "$SCRIPT_DIR/markdown/results.sarif"
+check_junit "rows carry severity, query id, file and line" \
+  grep -q '^<tr class="finding" data-severity="error"><td class="severity error" data-sort="3">error</td><td><code title="Storage key collision">cosmwasm/storage-key-collision</code></td><td>src/state.rs</td><td class="line" data-sort="11">11</td>' \
+  "$HTML_DIR/report.html"
+check_junit "page loads no external resources" \
+  bash -c '! grep -Eq "<(link|script) [^>]*(href|src)=" "$1"' _ "$HTML_DIR/report.html"
+
+# Rust generics in the message and the excerpt are escaped, not markup
+printf 'pub struct Batch {\n    pub amounts: Vec<Uint128>,\n}\n' > "$HTML_DIR/generics/src/msg.rs"
+jq '.runs[].results |= (.[:1] | map(
+      .locations[0].physicalLocation.artifactLocation.uri = "src/msg.rs"
+      | .locations[0].physicalLocation.region.startLine = 2
+      | .message.text = "amounts: Vec<Uint128> is unbounded"))' \
+  "$SCRIPT_DIR/markdown/results.sarif" > "$HTML_DIR/generics.sarif"
+bash "$SCRIPT_DIR/sarif-to-html.sh" "$HTML_DIR/generics" "$HTML_DIR/generics.sarif" \
+  --out "$HTML_DIR/generics.html"
+check_junit "Vec<Uint128> renders escaped" \
+  bash -c 'grep -q "<td>amounts: Vec&lt;Uint128&gt; is unbounded<details>" "$1" &&
+           grep -qF "<span class=\"hit\">   2 |     pub amounts: Vec&lt;Uint128&gt;,</span>" "$1" &&
+           ! grep -q "Vec<Uint128>" "$1"' \
+  _ "$HTML_DIR/generics.html"
+
+echo ""
+echo "--- LSP Diagnostics Tests ---"
+# Golden diagnostics from a fixed SARIF input (the Markdown one, with end
+# columns on the reported tokens) over the vulnerable fixture sources
+LSP_DIR="$DB_DIR/lsp"
+mkdir -p "$LSP_DIR/utf8/src"
+bash "$SCRIPT_DIR/sarif-to-lsp.sh" "$SCRIPT_DIR/lsp/results.sarif" --out "$LSP_DIR/golden.json"
+check_junit "output matches test/lsp/expected.json" \
+  diff -q "$SCRIPT_DIR/lsp/expected.json" "$LSP_DIR/golden.json"
+check_junit "utf-8 offsets equal utf-16 ones on ASCII sources" \
+  bash -c 'diff -q "$1" <(bash "$2" "$3" --source-root "$4" --position-encoding utf-8)' \
+  _ "$SCRIPT_DIR/lsp/expected.json" "$SCRIPT_DIR/sarif-to-lsp.sh" "$SCRIPT_DIR/lsp/results.sarif" \
+  "$FIXTURES_DIR/vulnerable-contract"
+
+# `a / b` after "café 🚀": UTF-16 columns 31-36 are bytes 34-39
+printf 'fn f() {\n    let s = "caf\xc3\xa9 \xf0\x9f\x9a\x80"; let q = a / b;\n}\n' > "$LSP_DIR/utf8/src/lib.rs"
+jq '.runs[].results |= (.[1:2] | map(
+      .locations[0].physicalLocation.artifactLocation.uri = "src/lib.rs"
+      | .locations[0].physicalLocation.region = {startLine: 2, startColumn: 32, endLine: 2, endColumn: 37}))' \
+  "$SCRIPT_DIR/markdown/results.sarif" > "$LSP_DIR/utf8.sarif"
+check_junit "utf-16 positions count code units" \
+  bash -c '[ "$(bash "$1" "$2" | jq -c ".[0].diagnostics[0].range")" = "{\"start\":{\"line\":1,\"character\":31},\"end\":{\"line\":1,\"character\":36}}" ]' \
+  _ "$SCRIPT_DIR/sarif-to-lsp.sh" "$LSP_DIR/utf8.sarif"
+check_junit "utf-8 positions count bytes" \
+  bash -c '[ "$(bash "$1" "$2" --source-root "$3" --position-encoding utf-8 | jq -c ".[0].diagnostics[0].range")" = "{\"start\":{\"line\":1,\"character\":34},\"end\":{\"line\":1,\"character\":39}}" ]' \
+  _ "$SCRIPT_DIR/sarif-to-lsp.sh" "$LSP_DIR/utf8.sarif" "$LSP_DIR/utf8"
+
+echo ""
+echo "--- JSON Lines Tests ---"
+# Streamed output must hold the same findings as the buffered SARIF, one
+# self-contained object per line
+JSONL_FILE="$DB_DIR/vulnerable-contract.jsonl"
+bash "$SCRIPT_DIR/sarif-to-jsonl.sh" "$DB_DIR/vulnerable-contract.sarif" --out "$JSONL_FILE"
+check_junit "every line parses on its own with all fields" \
+  bash -c 'while IFS= read -r line; do
+             jq -e "keys - [\"cwe\", \"help_uri\"] == [\"column\", \"end_column\", \"end_line\", \"file\", \"line\", \"message\", \"query\", \"severity\"]" <<< "$line" || exit 1
+           done < "$1"' \
+  _ "$JSONL_FILE"
+check_junit "line count matches the buffered run" \
+  bash -c '[ "$(wc -l < "$1")" -eq "$(jq "[.runs[].results[] | select((.suppressions // []) | length == 0)] | length" "$2")" ]' \
+  _ "$JSONL_FILE" "$DB_DIR/vulnerable-contract.sarif"
+check_junit "findings and order match the buffered run" \
+  bash -c 'diff <(jq -r "[.query, .file, .line] | @tsv" "$1") \
+    <(jq -r ".runs[].results[] | select((.suppressions // []) | length == 0)
+      | [.ruleId, .locations[0].physicalLocation.artifactLocation.uri,
+         .locations[0].physicalLocation.region.startLine] | @tsv" "$2")' \
+  _ "$JSONL_FILE" "$DB_DIR/vulnerable-contract.sarif"
+check_junit "default severities come from rule metadata" \
+  bash -c '[ "$(bash "$1" "$2" | jq -r "select(.query == \"cosmwasm/storage-key-collision\") | .severity")" = error ]' \
+  _ "$SCRIPT_DIR/sarif-to-jsonl.sh" "$SCRIPT_DIR/markdown/results.sarif"
+check_junit "help links and CWEs come from the registry" \
+  bash -c '[ "$(bash "$1" "$2" | jq -c "select(.query == \"cosmwasm/division-by-zero\") | [.help_uri, .cwe]")" = \
+             "$(jq -c ".helpBaseUri as \$base | .rules[] | select(.id == \"cosmwasm/division-by-zero\") | [\$base + .help, .cwe]" "$3")" ]' \
+  _ "$SCRIPT_DIR/sarif-to-jsonl.sh" "$SCRIPT_DIR/markdown/results.sarif" "$PROJECT_ROOT/src/registry/rules.json"
+
+echo ""
+echo "--- GitHub Annotations Tests ---"
+# Golden workflow commands from the fixed Markdown SARIF input over the
+# vulnerable fixture sources, then the real vulnerable run.
+GITHUB_DIR="$DB_DIR/github"
+mkdir -p "$GITHUB_DIR"
+bash "$SCRIPT_DIR/sarif-to-github.sh" "$SCRIPT_DIR/markdown/results.sarif" > "$GITHUB_DIR/golden.txt"
+check_junit "output matches test/github/expected.txt" \
+  diff -q "$SCRIPT_DIR/github/expected.txt" "$GITHUB_DIR/golden.txt"
+bash "$SCRIPT_DIR/sarif-to-github.sh" "$DB_DIR/vulnerable-contract.sarif" --out "$GITHUB_DIR/vulnerable.txt"
+check_junit "vulnerable-contract: one command per unsuppressed result" \
+  bash -c '[ "$(wc -l < "$1")" -eq "$(jq "[.runs[].results[] | select((.suppressions // []) | length == 0)] | length" "$2")" ]' \
+  _ "$GITHUB_DIR/vulnerable.txt" "$DB_DIR/vulnerable-contract.sarif"
+check_junit "vulnerable-contract: every command has file, 1-based line and col, and the rule id" \
+  bash -c '! grep -Ev "^::(error|warning|notice) file=src/[^,]+\.rs,line=[1-9][0-9]*,col=[1-9][0-9]*(,endLine=[1-9][0-9]*,endColumn=[1-9][0-9]*)?,title=cosmwasm/[a-z0-9-]+::." "$1"' \
+  _ "$GITHUB_DIR/vulnerable.txt"
+check_junit "--path-prefix is prepended to every file" \
+  bash -c '[ "$(bash "$1" "$2" --path-prefix contracts/token/ | grep -c "file=contracts/token/src/")" -eq 4 ]' \
+  _ "$SCRIPT_DIR/sarif-to-github.sh" "$SCRIPT_DIR/markdown/results.sarif"
+
+# Newlines and % in the message, a comma and a colon in the file name
+jq '.runs[].results |= (.[3:4] | map(
+      .locations[0].physicalLocation.artifactLocation.uri = "src/a,b:c.rs"
+      | .message.text = "100% of\nfunds"))' \
+  "$SCRIPT_DIR/markdown/results.sarif" > "$GITHUB_DIR/escaping.sarif"
+check_junit "workflow command values are escaped" \
+  bash -c '[ "$(bash "$1" "$2")" = "::error file=src/a%2Cb%3Ac.rs,line=11,col=1,title=cosmwasm/storage-key-collision::100%25 of%0Afunds" ]' \
+  _ "$SCRIPT_DIR/sarif-to-github.sh" "$GITHUB_DIR/escaping.sarif"
+
+echo ""
+echo "--- Source Span Tests ---"
+# Arithmetic findings underline the operator and unwrap findings the method
+# name, not the whole statement. SARIF end columns are one past the span.
+check_span() {
+  local rule="$1" uri="$2" line="$3" start="$4" end="$5"
+  check_junit "$rule at $uri:$line spans columns $start-$((end - 1))" \
+    jq -e --arg rule "$rule" --arg uri "$uri" --argjson line "$line" --argjson start "$start" --argjson stop "$end" '
+      [.runs[].results[] | select(.ruleId == $rule)
+        | .locations[0].physicalLocation
+        | select(.artifactLocation.uri == $uri and .region.startLine == $line)
+        | .region]
+      | length == 1 and .[0].startColumn == $start and .[0].endColumn == $stop
+        and (.[0].endLine // $line) == $line' \
+    "$SARIF_FILE"
+}
+# `config.total_supply + amount`: the `+`
+check_span cosmwasm/unchecked-cosmwasm-arithmetic src/contract.rs 179 47 48
+# `balance -= amount`: the `-=`
+check_span cosmwasm/unchecked-cosmwasm-arithmetic src/contract.rs 257 13 15
+# `pool_a / pool_b`: the `/`
+check_span cosmwasm/division-by-zero src/query.rs 61 15 16
+# `CONFIG.load(deps.storage).unwrap()`: `unwrap`
+check_span cosmwasm/unchecked-storage-unwrap src/contract.rs 169 48 54
+# `PROPOSALS.load(deps.storage, id).expect(..)`: `expect`
+check_span cosmwasm/unchecked-storage-unwrap src/query.rs 45 53 59
+check_junit "JSON Lines carry the span of the operator" \
+  bash -c '[ "$(jq -c "select(.query == \"cosmwasm/unchecked-cosmwasm-arithmetic\" and .line == 179) | [.column, .end_line, .end_column]" "$1")" = "[47,179,47]" ]' \
+  _ "$JSONL_FILE"
+check_junit "GitHub annotations underline the operator" \
+  grep -q "^::warning file=src/contract.rs,line=179,col=47,endLine=179,endColumn=47,title=cosmwasm/unchecked-cosmwasm-arithmetic::" \
+  "$GITHUB_DIR/vulnerable.txt"
+
+echo ""
+echo "--- Suppression Tests ---"
+# suppression-contract has one matched comment, one with two ids, and one
+# stale comment that matches nothing.
+SARIF_FILE="$DB_DIR/suppression-contract.sarif"
+codeql database analyze "$DB_DIR/suppression-contract-db" \
+  "$PROJECT_ROOT/src/queries/AlertSuppression.ql" \
+  "$PROJECT_ROOT/src/queries/data-safety/UncheckedCosmwasmArithmetic.ql" \
+  "$PROJECT_ROOT/src/queries/data-safety/LossyIntegerCast.ql" \
+  "$PROJECT_ROOT/src/queries/determinism/FloatUsage.ql" \
+  --additional-packs="$PROJECT_ROOT" \
+  --format=sarifv2.1.0 \
+  --output="$SARIF_FILE" \
+  --rerun >/dev/null 2>&1
+
+check_sarif "matched and multi-id comments suppress all 3 results" \
+  '[.runs[].results[] | select((.suppressions // []) | length > 0)] | length == 3'
+check_sarif "no unsuppressed results remain" \
+  '[.runs[].results[] | select((.suppressions // []) | length == 0)] | length == 0'
+check_sarif "the reason is recorded as the justification" \
+  '[.runs[].results[].suppressions[]?.justification] | index("amount is capped by the deposit limit") != null'
+
+unused=$(bash "$SCRIPT_DIR/unused-suppressions.sh" "$FIXTURES_DIR/suppression-contract" "$SARIF_FILE")
+if [ "$(echo "$unused" | grep -c "cosmwasm/float-usage")" -eq 1 ] && [ "$(echo "$unused" | grep -c "^warning:")" -eq 1 ]; then
+  echo "  $(green PASS) stale suppression reported as unused"
+  PASS=$((PASS + 1))
+else
+  echo "  $(red FAIL) stale suppression reported as unused: ${unused:-<none>}"
+  FAIL=$((FAIL + 1))
+fi
+
+# The expiry-boundary review aid is meant to be silenced line by line once
+# a comparison is checked: one of its two results is suppressed
+SARIF_FILE="$DB_DIR/vulnerable-contract.expiry.sarif"
+codeql database analyze "$DB_DIR/vulnerable-contract-db" \
+  "$PROJECT_ROOT/src/queries/AlertSuppression.ql" \
+  "$PROJECT_ROOT/src/queries/data-safety/ExpiryBoundary.ql" \
+  --additional-packs="$PROJECT_ROOT" \
+  --format=sarifv2.1.0 \
+  --output="$SARIF_FILE" \
+  --rerun >/dev/null 2>&1
+
+check_sarif "a reviewed expiry comparison is suppressed" \
+  '[.runs[].results[] | select((.suppressions // []) | length > 0)] | length == 1'
+check_sarif "the unreviewed expiry comparison is still reported" \
+  '[.runs[].results[] | select((.suppressions // []) | length == 0) | .locations[0].physicalLocation.region.startLine] == [21]'
+
+echo ""
+echo "--- Baseline Tests ---"
+# Baseline from the vulnerable fixture, then diff a copy whose sources are
+# shifted down by 7 lines and that adds one collision: only that one is new.
+BASELINE_FILE="$DB_DIR/vulnerable-contract.baseline.json"
+bash "$SCRIPT_DIR/diff-baseline.sh" "$FIXTURES_DIR/vulnerable-contract" "$DB_DIR/vulnerable-contract.sarif" \
+  --write-baseline "$BASELINE_FILE" 2>/dev/null
+
+SHIFTED_DIR="$DB_DIR/shifted-contract"
+if [ ! -d "$DB_DIR/shifted-contract-db" ] || [ "$REBUILD" = "--rebuild" ]; then
+  rm -rf "$SHIFTED_DIR"
+  cp -r "$FIXTURES_DIR/vulnerable-contract" "$SHIFTED_DIR"
+  for rs in "$SHIFTED_DIR"/src/*.rs; do
+    { printf '//\n%.0s' 1 2 3 4 5 6 7; cat "$rs"; } > "$rs.tmp" && mv "$rs.tmp" "$rs"
+  done
+  printf '\npub const CONFIG_V2: Item<Config> = Item::new("config");\n' >> "$SHIFTED_DIR/src/state.rs"
+  codeql database create "$DB_DIR/shifted-contract-db" \
+    --language=rust \
+    --source-root="$SHIFTED_DIR" \
+    --overwrite \
+    2>&1 | tail -1 >/dev/null
+fi
+write_overflow_checks
+codeql database analyze "$DB_DIR/shifted-contract-db" "${sarif_queries[@]}" \
+  --additional-packs="$PROJECT_ROOT" \
+  --format=sarifv2.1.0 \
+  --output="$DB_DIR/shifted-contract.sarif" \
+  --rerun >/dev/null 2>&1
+
+new_findings=$(bash "$SCRIPT_DIR/diff-baseline.sh" "$SHIFTED_DIR" "$DB_DIR/shifted-contract.sarif" \
+  --baseline "$BASELINE_FILE" 2>/dev/null || true)
+new_count=$(printf '%s\n' "$new_findings" | grep -c . || true)
+if [ "$new_count" -eq 1 ] && [ "$(printf '%s\n' "$new_findings" | cut -f1)" = "cosmwasm/storage-key-collision" ]; then
+  echo "  $(green PASS) shifted sources report only the added finding"
+  PASS=$((PASS + 1))
+else
+  echo "  $(red FAIL) shifted sources report only the added finding: $new_count new"
+  printf '%s\n' "$new_findings" | sed 's/^/    /'
+  FAIL=$((FAIL + 1))
+fi
+
+echo ""
+echo "--- Configuration Tests ---"
+# Each .cosmwasm-codeql.toml setting applied to the vulnerable-contract SARIF.
+CONFIG_DIR="$DB_DIR/config"
+mkdir -p "$CONFIG_DIR"
+VULN_SARIF="$DB_DIR/vulnerable-contract.sarif"
+
+printf 'disabled_queries = ["ibc-cei-violation"]\n' > "$CONFIG_DIR/disable.toml"
+bash "$SCRIPT_DIR/apply-config.sh" "$FIXTURES_DIR/vulnerable-contract" "$VULN_SARIF" \
+  --config "$CONFIG_DIR/disable.toml" --out "$CONFIG_DIR/disable.sarif"
+check_junit "disabled_queries drops only that query's results" \
+  jq -e --slurpfile orig "$VULN_SARIF" '
+    ([.runs[].results[] | select(.ruleId == "cosmwasm/ibc-cei-violation")] | length == 0)
+    and ([.runs[].results[]] | length)
+      == ([$orig[0].runs[].results[] | select(.ruleId != "cosmwasm/ibc-cei-violation")] | length)' \
+  "$CONFIG_DIR/disable.sarif"
+
+# An intentionally non-migratable contract opts out of the advisory cw2 query
+printf 'disabled_queries = ["missing-cw2-version"]\n' > "$CONFIG_DIR/non-migratable.toml"
+bash "$SCRIPT_DIR/apply-config.sh" "$FIXTURES_DIR/vulnerable-contract" "$VULN_SARIF" \
+  --config "$CONFIG_DIR/non-migratable.toml" --out "$CONFIG_DIR/non-migratable.sarif"
+check_junit "disabled_queries turns off missing-cw2-version" \
+  jq -e --slurpfile orig "$VULN_SARIF" '
+    ([$orig[0].runs[].results[] | select(.ruleId == "cosmwasm/missing-cw2-version")] | length == 1)
+    and ([.runs[].results[] | select(.ruleId == "cosmwasm/missing-cw2-version")] | length == 0)' \
+  "$CONFIG_DIR/non-migratable.sarif"
+
+# Teams that omit response attributes on purpose opt out of the posture
+# query, which is not in TESTS (the safe fixture reports it too)
+codeql database analyze "$DB_DIR/vulnerable-contract-db" \
+  "$PROJECT_ROOT/src/queries/data-safety/NoEventsEmitted.ql" \
+  --additional-packs="$PROJECT_ROOT" \
+  --format=sarifv2.1.0 \
+  --output="$CONFIG_DIR/events.sarif" \
+  --rerun >/dev/null 2>&1
+printf 'disabled_queries = ["no-events-emitted"]\n' > "$CONFIG_DIR/no-events.toml"
+bash "$SCRIPT_DIR/apply-config.sh" "$FIXTURES_DIR/vulnerable-contract" "$CONFIG_DIR/events.sarif" \
+  --config "$CONFIG_DIR/no-events.toml" --out "$CONFIG_DIR/no-events.sarif"
+check_junit "disabled_queries turns off no-events-emitted" \
+  jq -e --slurpfile orig "$CONFIG_DIR/events.sarif" '
+    ([$orig[0].runs[].results[] | select(.ruleId == "cosmwasm/no-events-emitted")] | length == 2)
+    and ([.runs[].results[] | select(.ruleId == "cosmwasm/no-events-emitted")] | length == 0)' \
+  "$CONFIG_DIR/no-events.sarif"
+
+printf 'severity_overrides = { "cosmwasm/unchecked-cosmwasm-arithmetic" = "error" }\n' > "$CONFIG_DIR/override.toml"
+bash "$SCRIPT_DIR/apply-config.sh" "$FIXTURES_DIR/vulnerable-contract" "$VULN_SARIF" \
+  --config "$CONFIG_DIR/override.toml" --out "$CONFIG_DIR/override.sarif"
+check_junit "severity_overrides raises the query's results to error" \
+  jq -e '[.runs[].results[] | select(.ruleId == "cosmwasm/unchecked-cosmwasm-arithmetic") | .level == "error"]
+    | length > 0 and all' "$CONFIG_DIR/override.sarif"
+check_junit "overridden results are reported by --fail-on error" \
+  bash -c 'bash "$1" "$2" --fail-on error 2>/dev/null | grep -q "^cosmwasm/unchecked-cosmwasm-arithmetic"' \
+  _ "$SCRIPT_DIR/fail-on.sh" "$CONFIG_DIR/override.sarif"
+
+# src/ibc.rs has findings; src/**/ibc.rs must match it at any depth, including none
+printf 'exclude_paths = [\n  "src/testing/**",\n  "src/**/ibc.rs",\n]\n' > "$CONFIG_DIR/exclude.toml"
+bash "$SCRIPT_DIR/apply-config.sh" "$FIXTURES_DIR/vulnerable-contract" "$VULN_SARIF" \
+  --config "$CONFIG_DIR/exclude.toml" --out "$CONFIG_DIR/exclude.sarif"
+check_junit "exclude_paths drops every result in the matched file" \
+  jq -e '[.runs[].results[].locations[0].physicalLocation.artifactLocation.uri] | (index("src/ibc.rs") == null) and length > 0' \
+  "$CONFIG_DIR/exclude.sarif"
+check_junit "vulnerable-contract has findings in src/ibc.rs to exclude" \
+  jq -e '[.runs[].results[].locations[0].physicalLocation.artifactLocation.uri] | index("src/ibc.rs") != null' "$VULN_SARIF"
+
+printf 'disabled_queries = "ibc-cei-violation"\n' > "$CONFIG_DIR/invalid.toml"
+check_junit "invalid config is rejected with exit 2" \
+  bash -c 'bash "$1" "$2" "$3" --config "$4" >/dev/null 2>&1; [ $? -eq 2 ]' \
+  _ "$SCRIPT_DIR/apply-config.sh" "$FIXTURES_DIR/vulnerable-contract" "$VULN_SARIF" "$CONFIG_DIR/invalid.toml"
+echo ""
+echo "--- Workspace Tests ---"
+# Two-member workspace (vulnerable + safe fixture) analyzed as one database;
+# findings must be attributed to the crate they come from.
+WS_DIR="$DB_DIR/workspace"
+if [ ! -d "$WS_DIR" ] || [ "$REBUILD" = "--rebuild" ]; then
+  rm -rf "$WS_DIR"
+  mkdir -p "$WS_DIR/contracts"
+  cp -r "$FIXTURES_DIR/vulnerable-contract" "$FIXTURES_DIR/safe-contract" "$WS_DIR/contracts/"
+  printf '[workspace]\nmembers = ["contracts/*"]\nresolver = "2"\n\n[profile.release]\noverflow-checks = true\n' \
+    > "$WS_DIR/Cargo.toml"
+fi
+write_overflow_checks
+ws_args=("$WS_DIR" --out "$DB_DIR/workspace-out")
+[ "$REBUILD" = "--rebuild" ] && ws_args+=(--rebuild)
+ws_findings=$(bash "$SCRIPT_DIR/analyze-workspace.sh" "${ws_args[@]}" 2>/dev/null || true)
+
+for test_spec in "${TESTS[@]}"; do
+  query="${test_spec%%:*}"
+  expected="${test_spec##*:}"
+  name=$(basename "$query" .ql)
+  rule_id=$(sed -n 's/^ \* @id //p' "$PROJECT_ROOT/$query")
+
+  vuln=$(printf '%s\n' "$ws_findings" | awk -F'\t' -v r="$rule_id" '$1 == "vulnerable-contract" && $2 == r' | grep -c . || true)
+  safe=$(printf '%s\n' "$ws_findings" | awk -F'\t' -v r="$rule_id" '$1 == "safe-contract" && $2 == r' | grep -c . || true)
+
+  if [ "$vuln" -eq "$expected" ] && [ "$safe" -eq 0 ]; then
+    echo "  $(green PASS) $name: vulnerable-contract $vuln, safe-contract 0"
+    PASS=$((PASS + 1))
+  else
+    echo "  $(red FAIL) $name: vulnerable-contract $vuln (expected $expected), safe-contract $safe (expected 0)"
+    FAIL=$((FAIL + 1))
+  fi
+done
+
+# CodeQL leaves helpUri unset for local packs; the workspace SARIF links each rule
+check_junit "workspace SARIF: every rule has a non-empty helpUri" \
+  jq -e "$SARIF_RULES | map(.helpUri // \"\" | length > 0) | (length > 0 and all)" \
+  "$DB_DIR/workspace-out/results.sarif"
+
+# Workflow commands for the same findings, paths relative to the workspace
+ws_github=$(bash "$SCRIPT_DIR/analyze-workspace.sh" "$WS_DIR" --out "$DB_DIR/workspace-out" --format github 2>/dev/null || true)
+check_junit "--format github: one workflow command per finding" \
+  test "$(printf '%s\n' "$ws_github" | grep -c "^::[a-z]* file=contracts/")" -eq "$(printf '%s\n' "$ws_findings" | grep -c .)"
+
+# Thread count must not change the findings or their order
+ws_serial=$(bash "$SCRIPT_DIR/analyze-workspace.sh" "$WS_DIR" --out "$DB_DIR/workspace-out" --jobs 1 2>/dev/null || true)
+ws_parallel=$(bash "$SCRIPT_DIR/analyze-workspace.sh" "$WS_DIR" --out "$DB_DIR/workspace-out" --jobs 8 2>/dev/null || true)
+if [ -n "$ws_serial" ] && [ "$ws_serial" = "$ws_parallel" ]; then
+  echo "  $(green PASS) --jobs 1 and --jobs 8: identical findings ($(printf '%s\n' "$ws_serial" | grep -c .))"
+  PASS=$((PASS + 1))
+else
+  echo "  $(red FAIL) --jobs 1 and --jobs 8: findings differ"
+  diff <(printf '%s\n' "$ws_serial") <(printf '%s\n' "$ws_parallel") | head -10 | sed 's/^/    /'
+  FAIL=$((FAIL + 1))
+fi
+
+# A profile runs a subset of the queries and finds what the full run finds for them
+ws_standard=$(bash "$SCRIPT_DIR/analyze-workspace.sh" "$WS_DIR" --out "$DB_DIR/workspace-out" --profile standard 2>/dev/null || true)
+check_junit "--profile standard: the full run's findings for the standard rules" \
+  bash -c 'diff <(printf "%s\n" "$1" | grep .) \
+    <(printf "%s\n" "$2" | awk -F"\t" "NR == FNR { keep[\$1]; next } \$2 in keep" <(bash "$3" --profile standard) - | grep .)' \
+  _ "$ws_standard" "$ws_findings" "$SCRIPT_DIR/rules.sh"
+
+check_junit "an unknown --fail-on severity is a usage error" \
+  bash -c 'bash "$1" "$2" --out "$3" --fail-on critical 2>/dev/null; [ $? -eq 2 ]' \
+  _ "$SCRIPT_DIR/analyze-workspace.sh" "$WS_DIR" "$DB_DIR/workspace-out"
+
+echo ""
+echo "--- Incremental Analysis Tests ---"
+# A workspace of incremental-contract and split-reply-contract analyzed with
+# --cache-dir: an unchanged re-run reuses every finding, and editing a file
+# re-analyzes every file of its crate and none of the other. Editing the
+# file that builds split-reply-contract's SubMsg keeps reply.rs, which
+# handles the reply but is neither its caller nor its callee, in the
+# database, so the SubMsg is not reported as unhandled.
+INC_DIR="$DB_DIR/incremental"
+rm -rf "$INC_DIR"
+mkdir -p "$INC_DIR/ws/contracts"
+cp -r "$FIXTURES_DIR/incremental-contract" "$FIXTURES_DIR/split-reply-contract" "$INC_DIR/ws/contracts/"
+printf '[workspace]\nmembers = ["contracts/*"]\nresolver = "2"\n' > "$INC_DIR/ws/Cargo.toml"
+inc_run() {
+  bash "$SCRIPT_DIR/analyze-workspace.sh" "$INC_DIR/ws" --out "$INC_DIR/out" \
+    --cache-dir "$INC_DIR/cache" 2>"$INC_DIR/stderr" || true
+}
+reanalyzed() {
+  sed -n 's/^Re-analyzing: //p' "$INC_DIR/stderr" | sort | tr '\n' ' '
+}
+
+inc_full=$(inc_run)
+check_junit "first run analyzes every source file" \
+  bash -c '[ "$(grep -c "^Re-analyzing: " "$1")" -eq 13 ]' _ "$INC_DIR/stderr"
+check_junit "first run reports findings" test -n "$inc_full"
+
+inc_same=$(inc_run)
+check_junit "unchanged re-run re-analyzes nothing" \
+  bash -c '! grep -q "^Re-analyzing: " "$1" && grep -q "^No source changes" "$1"' _ "$INC_DIR/stderr"
+check_junit "unchanged re-run reuses the same findings" test "$inc_full" = "$inc_same"
+
+printf '// edited\n' >> "$INC_DIR/ws/contracts/incremental-contract/src/helpers.rs"
+inc_edit=$(inc_run)
+check_junit "editing helpers.rs re-analyzes its crate only" \
+  test "$(reanalyzed)" = "$(cd "$INC_DIR/ws" && find contracts/incremental-contract -name '*.rs' | sort | tr '\n' ' ')"
+check_junit "findings after the edit match the first run" test "$inc_full" = "$inc_edit"
+
+printf '// edited\n' >> "$INC_DIR/ws/contracts/split-reply-contract/src/submsg.rs"
+inc_submsg=$(inc_run)
+check_junit "editing submsg.rs re-analyzes its crate, reply.rs included" \
+  test "$(reanalyzed)" = "$(cd "$INC_DIR/ws" && find contracts/split-reply-contract -name '*.rs' | sort | tr '\n' ' ')"
+check_junit "findings after the SubMsg edit match the first run" test "$inc_full" = "$inc_submsg"
+check_junit "a SubMsg handled in another file is not reported as unhandled" \
+  bash -c '! grep -q "submsg-without-reply-handler" <<< "$1"' _ "$inc_submsg"
+
+echo ""
+echo "--- Changed-Files Analysis Tests ---"
+# The incremental workspace analyzed with --changed-files: a list naming
+# helpers.rs, a deleted file and a README scans incremental-contract only,
+# and reports only its findings; a list naming split-reply-contract's
+# submsg.rs scans that crate, reply.rs included, so its SubMsg is not
+# reported as unhandled.
+DIFF_DIR="$DB_DIR/changed-files"
+rm -rf "$DIFF_DIR"
+mkdir -p "$DIFF_DIR"
+printf '%s\n' "contracts/incremental-contract/src/helpers.rs" "./contracts/incremental-contract/src/removed.rs" \
+  "README.md" > "$DIFF_DIR/changed.txt"
+diff_findings=$(bash "$SCRIPT_DIR/analyze-workspace.sh" "$INC_DIR/ws" --out "$DIFF_DIR/out" \
+  --changed-files "$DIFF_DIR/changed.txt" 2>"$DIFF_DIR/stderr" || true)
+scanned() {
+  sed -n 's/^Scanning: //p' "$DIFF_DIR/stderr" | sort | tr '\n' ' '
+}
+check_junit "only the changed file's crate is scanned" \
+  test "$(scanned)" = "$(cd "$INC_DIR/ws" && find contracts/incremental-contract -name '*.rs' | sort | tr '\n' ' ')"
+check_junit "a deleted file is reported and skipped" \
+  grep -qx "Deleted: contracts/incremental-contract/src/removed.rs" "$DIFF_DIR/stderr"
+check_junit "findings are the full run's findings in the scanned crate" \
+  test "$diff_findings" = "$(printf '%s\n' "$inc_full" | grep $'\tcontracts/incremental-contract/')"
+printf '%s\n' "contracts/split-reply-contract/src/submsg.rs" > "$DIFF_DIR/submsg.txt"
+diff_submsg=$(bash "$SCRIPT_DIR/analyze-workspace.sh" "$INC_DIR/ws" --out "$DIFF_DIR/out" \
+  --changed-files "$DIFF_DIR/submsg.txt" 2>"$DIFF_DIR/stderr" || true)
+check_junit "a changed submsg.rs scans its crate, reply.rs included" \
+  test "$(scanned)" = "$(cd "$INC_DIR/ws" && find contracts/split-reply-contract -name '*.rs' | sort | tr '\n' ' ')"
+check_junit "findings are the full run's findings in the SubMsg's crate" \
+  test "$diff_submsg" = "$(printf '%s\n' "$inc_full" | grep $'\tcontracts/split-reply-contract/' || true)"
+check_junit "a SubMsg handled in another file is not reported as unhandled" \
+  bash -c '! grep -q "submsg-without-reply-handler" <<< "$1"' _ "$diff_submsg"
+: > "$DIFF_DIR/empty.txt"
+check_junit "an empty list scans nothing" \
+  bash -c 'bash "$1" "$2" --out "$3/out" --changed-files "$3/empty.txt" 2>&1 >/dev/null | grep -q "^No changed Rust sources"' \
+  _ "$SCRIPT_DIR/analyze-workspace.sh" "$INC_DIR/ws" "$DIFF_DIR"
+check_junit "an unknown --diff-base ref is a usage error" \
+  bash -c 'bash "$1" "$2" --out "$3/out" --diff-base no-such-ref 2>/dev/null; [ $? -eq 2 ]' \
+  _ "$SCRIPT_DIR/analyze-workspace.sh" "$INC_DIR/ws" "$DIFF_DIR"
+
+echo ""
+echo "--- Rule Registry Tests ---"
+# src/registry/rules.json against the queries, the SARIF CodeQL writes, and
+# the writers' fallback when a SARIF file carries no rule descriptors.
+REGISTRY_DIR="$DB_DIR/registry"
+mkdir -p "$REGISTRY_DIR"
+check_junit "every query has a registry entry and every entry a query" bash "$SCRIPT_DIR/rules.sh" --check
+check_junit "--list-rules prints one line per problem query" \
+  bash -c '[ "$(bash "$1" --list-rules | wc -l)" -eq "$(grep -rl "^ \* @kind problem" "$2" | wc -l)" ]' \
+  _ "$SCRIPT_DIR/analyze-workspace.sh" "$PROJECT_ROOT/src/queries"
+check_sarif "SARIF rule levels and precisions match the registry" \
+  "($(bash "$SCRIPT_DIR/rules.sh" --sarif-rules) | map({key: .id, value: .}) | from_entries) as \$registry
+   | $SARIF_RULES | map(\$registry[.id] as \$entry
+       | \$entry != null
+         and .defaultConfiguration.level == \$entry.defaultConfiguration.level
+         and .properties.precision == \$entry.properties.precision) | (length > 0 and all)"
+check_junit "SARIF rules link to their help page" \
+  bash -c 'bash "$1" --sarif-rules | jq -e --arg base "$(jq -r .helpBaseUri "$2")" --slurpfile registry "$2" "
+    (\$registry[0].rules | map({key: .id, value: .help}) | from_entries) as \$help
+    | length > 0 and all(.helpUri == \$base + \$help[.id])"' \
+  _ "$SCRIPT_DIR/rules.sh" "$PROJECT_ROOT/src/registry/rules.json"
+check_junit "every help page has Description text" \
+  bash -c 'jq -r ".rules[].help" "$1" | while read -r help; do
+             awk "/^## / { s = (\$0 == \"## Description\"); next } s && NF { f = 1 } END { exit !f }" "$2/$help" || exit 1
+           done' \
+  _ "$PROJECT_ROOT/src/registry/rules.json" "$PROJECT_ROOT"
+
+# Each profile's rule ids, against its definition and the profile it extends
+for profile in standard strict audit; do
+  bash "$SCRIPT_DIR/rules.sh" --profile "$profile" | cut -f1 | sort > "$REGISTRY_DIR/$profile.ids"
+done
+check_junit "standard: the security rules at error or warning with high precision" \
+  diff "$REGISTRY_DIR/standard.ids" <(jq -r '.rules[] | select((.tags | index("security"))
+    and (.severity == "error" or .severity == "warning") and (.precision == "high" or .precision == "very-high"))
+    | .id' "$PROJECT_ROOT/src/registry/rules.json" | sort)
+check_junit "strict: every rule at error or warning with at least medium precision, and the posture rules" \
+  diff "$REGISTRY_DIR/strict.ids" <(jq -r '.rules[] | select(.severity != "recommendation" and .precision != "low"
+    or (.tags | index("posture"))) | .id' "$PROJECT_ROOT/src/registry/rules.json" | sort)
+check_junit "strict includes at least one posture rule" \
+  bash -c 'jq -r ".rules[] | select(.tags | index(\"posture\")) | .id" "$1" | grep -qxFf - "$2"' \
+  _ "$PROJECT_ROOT/src/registry/rules.json" "$REGISTRY_DIR/strict.ids"
+check_junit "audit: every rule" \
+  diff "$REGISTRY_DIR/audit.ids" <(jq -r '.rules[].id' "$PROJECT_ROOT/src/registry/rules.json" | sort)
+check_junit "audit includes strict, and strict includes standard" \
+  bash -c '[ -s "$1" ] && [ -z "$(comm -23 "$1" "$2")" ] && [ -z "$(comm -23 "$2" "$3")" ]' \
+  _ "$REGISTRY_DIR/standard.ids" "$REGISTRY_DIR/strict.ids" "$REGISTRY_DIR/audit.ids"
+check_junit "an unknown profile is rejected with exit 2" \
+  bash -c 'bash "$1" --profile paranoid >/dev/null 2>&1; [ $? -eq 2 ]' _ "$SCRIPT_DIR/rules.sh"
+check_junit "--explain prints the remediation and CWE of a rule" \
+  bash -c 'out=$(bash "$1" --explain unchecked-cosmwasm-arithmetic) &&
+           grep -q "checked_add" <<<"$out" && grep -q "CWE-190" <<<"$out" && ! grep -q "^\`\`\`" <<<"$out"' \
+  _ "$SCRIPT_DIR/rules.sh"
+check_junit "--explain --format md keeps the help page Markdown" \
+  bash -c 'out=$(bash "$1" --explain cosmwasm/unchecked-cosmwasm-arithmetic --format md) &&
+           grep -qx "## Recommendation" <<<"$out" && grep -qx "\`\`\`rust" <<<"$out" &&
+           grep -qF "[CWE-190](https://cwe.mitre.org/data/definitions/190.html)" <<<"$out"' \
+  _ "$SCRIPT_DIR/rules.sh"
+check_junit "--explain rejects an unknown rule with exit 2" \
+  bash -c 'bash "$1" --explain no-such-rule >/dev/null 2>&1; [ $? -eq 2 ]' _ "$SCRIPT_DIR/rules.sh"
+jq 'del(.runs[].tool)' "$SARIF_FILE" > "$REGISTRY_DIR/no-rules.sarif"
+check_junit "writers take levels from the registry when SARIF has no rules" \
+  bash -c 'diff -q <(bash "$1" "$2" --fail-on warning 2>&1) <(bash "$1" "$3" --fail-on warning 2>&1)' \
+  _ "$SCRIPT_DIR/fail-on.sh" "$SARIF_FILE" "$REGISTRY_DIR/no-rules.sarif"
+
 echo ""
 echo "=== Results: $(green "$PASS passed"), $(red "$FAIL failed") ==="
 
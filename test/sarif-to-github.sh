@@ -0,0 +1,81 @@
+#!/bin/bash
+# Converts CodeQL SARIF output to GitHub Actions workflow commands, so
+# findings show up as inline annotations on pull requests without uploading
+# SARIF to code scanning.
+#
+# Usage:
+#   ./test/sarif-to-github.sh <results.sarif>                            # Commands on stdout
+#   ./test/sarif-to-github.sh <results.sarif> --path-prefix contracts/token
+#   ./test/sarif-to-github.sh <results.sarif> --out FILE                 # Write to a file
+#
+# Prints one command per unsuppressed result, sorted by file, line, column
+# and rule id:
+#
+#   ::warning file=src/contract.rs,line=42,col=5,title=cosmwasm/...::message
+#
+# Severity maps to the command: error to ::error, warning to ::warning, note
+# and none to ::notice. Lines and columns are 1-based, as GitHub expects and
+# SARIF reports them; a result without a column gets col=1. When the result
+# has an end column, endLine and endColumn (the last column of the span,
+# where SARIF's endColumn is one past it) are added, so the annotation
+# underlines just the reported token:
+#
+#   ::warning file=src/contract.rs,line=42,col=25,endLine=42,endColumn=25,title=...::message
+#
+# File paths are
+# the SARIF ones, relative to the analyzed source root: pass --path-prefix
+# when that is not the repository root. Values are escaped as the workflow
+# command syntax requires (%, CR and LF; also : and , in properties).
+#
+# Requirements: jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+
+SARIF_FILE=""
+PATH_PREFIX=""
+OUT_FILE=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --path-prefix) PATH_PREFIX="${2%/}"; shift 2 ;;
+    --out) OUT_FILE="$2"; shift 2 ;;
+    *) SARIF_FILE="$1"; shift ;;
+  esac
+done
+
+if [ ! -f "$SARIF_FILE" ]; then
+  echo "usage: sarif-to-github.sh <results.sarif> [--path-prefix DIR] [--out FILE]" >&2
+  exit 2
+fi
+
+to_commands() {
+  jq -r --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" --arg prefix "$PATH_PREFIX" '
+    def command: {"error": "error", "warning": "warning", "note": "notice", "none": "notice"}[.] // "warning";
+    def esc_data: tostring | gsub("%"; "%25") | gsub("\r"; "%0D") | gsub("\n"; "%0A");
+    def esc_property: esc_data | gsub(":"; "%3A") | gsub(","; "%2C");
+    ([$registry[], .runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?]
+     | map({key: .id, value: .}) | from_entries) as $rules
+    | [.runs[].results[]
+        | select((.suppressions // []) | length == 0)
+        | .locations[0].physicalLocation as $loc
+        | {
+            level: (.level // $rules[.ruleId].defaultConfiguration.level // "warning"),
+            rule: .ruleId,
+            file: (if $prefix == "" then $loc.artifactLocation.uri else "\($prefix)/\($loc.artifactLocation.uri)" end),
+            line: $loc.region.startLine,
+            column: ($loc.region.startColumn // 1),
+            end: (if $loc.region.endColumn then
+                    ",endLine=\($loc.region.endLine // $loc.region.startLine),endColumn=\($loc.region.endColumn - 1)"
+                  else "" end),
+            message: .message.text
+          }]
+    | sort_by(.file, .line, .column, .rule)[]
+    | "::\(.level | command) file=\(.file | esc_property),line=\(.line),col=\(.column)\(.end),title=\(.rule | esc_property)::\(.message | esc_data)"
+  ' "$SARIF_FILE"
+}
+
+if [ -n "$OUT_FILE" ]; then
+  to_commands > "$OUT_FILE"
+else
+  to_commands
+fi
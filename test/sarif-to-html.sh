@@ -0,0 +1,244 @@
+#!/bin/bash
+# Renders CodeQL SARIF output as a self-contained HTML page for auditors who
+# browse results without a CI integration.
+#
+# Usage:
+#   ./test/sarif-to-html.sh <source-root> <results.sarif>              # HTML on stdout
+#   ./test/sarif-to-html.sh <source-root> <results.sarif> --out FILE   # Write to a file
+#
+# The page is one table of findings (severity, query id, file, line,
+# message) that sorts by clicking a column header and filters by severity
+# or free text. Each row has a collapsible three-line excerpt read from
+# <source-root> with the reported line highlighted. CSS and JavaScript are
+# inline; the page loads nothing else. Messages and code are HTML-escaped.
+# Suppressed results are ignored. Above the findings, a table of the
+# contract's entry points from test/entry-points.sh shows which standard
+# handlers are present and which are missing.
+#
+# Requirements: jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+
+SOURCE_ROOT=""
+SARIF_FILE=""
+OUT_FILE=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --out) OUT_FILE="$2"; shift 2 ;;
+    *)
+      if [ -z "$SOURCE_ROOT" ]; then SOURCE_ROOT="$1"; else SARIF_FILE="$1"; fi
+      shift
+      ;;
+  esac
+done
+
+if [ -z "$SOURCE_ROOT" ] || [ ! -f "$SARIF_FILE" ]; then
+  echo "usage: sarif-to-html.sh <source-root> <results.sarif> [--out FILE]" >&2
+  exit 2
+fi
+
+# Prints one tab-separated line per unsuppressed result (severity rank,
+# severity, rule id, rule name, file, line, message), ordered by severity,
+# rule, file and line.
+list_results() {
+  jq -r --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" '
+    def rank: {"error": 3, "warning": 2, "note": 1, "none": 0}[.] // 2;
+    ([$registry[], .runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?]
+     | map({key: .id, value: .}) | from_entries) as $rules
+    | [.runs[].results[]
+        | select((.suppressions // []) | length == 0)
+        | .locations[0].physicalLocation as $loc
+        | (.level // $rules[.ruleId].defaultConfiguration.level // "warning") as $severity
+        | {
+            rank: ($severity | rank),
+            severity: $severity,
+            rule: .ruleId,
+            name: ($rules[.ruleId].shortDescription.text // .ruleId),
+            file: $loc.artifactLocation.uri,
+            line: $loc.region.startLine,
+            message: .message.text
+          }]
+    | sort_by(-.rank, .rule, .file, .line)[]
+    | [.rank, .severity, .rule, .name, .file, .line, .message]
+    | @tsv
+  ' "$SARIF_FILE"
+}
+
+escape() {
+  sed 's/&/\&amp;/g; s/</\&lt;/g; s/>/\&gt;/g; s/"/\&quot;/g' <<< "$1"
+}
+
+# Prints the excerpt lines of the file (test/excerpt-range.sh), escaped,
+# with the reported line highlighted.
+excerpt() {
+  local file="$1" line="$2" range first last
+  range=$(bash "$SCRIPT_DIR/excerpt-range.sh" "$file" "$line")
+  [ -n "$range" ] || return 0
+  IFS=$'\t' read -r first last <<< "$range"
+  awk -v first="$first" -v last="$last" -v hit="$line" '
+    function esc(s) {
+      gsub(/&/, "\\&amp;", s); gsub(/</, "\\&lt;", s); gsub(/>/, "\\&gt;", s)
+      return s
+    }
+    NR >= first && NR <= last {
+      text = sprintf("%4d | %s", NR, esc($0))
+      print (NR == hit ? "<span class=\"hit\">" text "</span>" : text)
+    }
+    NR > last { exit }
+  ' "$file"
+}
+
+# Prints the entry point table, if the source root defines any.
+entry_point_table() {
+  local handlers
+  handlers=$(bash "$SCRIPT_DIR/entry-points.sh" "$SOURCE_ROOT")
+  [ -n "$handlers" ] || return 0
+  echo "<h2>Entry Points</h2>"
+  echo "<table id=\"entry-points\">"
+  echo "<thead><tr><th>Handler</th><th>Status</th><th>Location</th></tr></thead>"
+  echo "<tbody>"
+  while IFS=$'\t' read -r handler status location; do
+    printf '<tr class="entry-point %s"><td><code>%s</code></td><td>%s</td><td>%s</td></tr>\n' \
+      "$status" "$(escape "$handler")" "$status" "$(escape "$location")"
+  done <<< "$handlers"
+  echo "</tbody>"
+  echo "</table>"
+}
+
+write_html() {
+  local results count summary=""
+  results=$(list_results)
+  count=$(printf '%s\n' "$results" | grep -c . || true)
+  for level in error warning note; do
+    local n
+    n=$(printf '%s\n' "$results" | awk -F'\t' -v l="$level" '$2 == l' | grep -c . || true)
+    [ "$n" -gt 0 ] && summary+="${summary:+, }$level: $n"
+  done
+
+  cat <<'HTML'
+<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>CosmWasm CodeQL Report</title>
+<style>
+  body { font-family: system-ui, sans-serif; margin: 2rem; color: #1f2328; }
+  .controls { margin: 1rem 0; display: flex; gap: 0.5rem; }
+  .controls input { flex: 1; max-width: 30rem; padding: 0.3rem; }
+  table { border-collapse: collapse; width: 100%; }
+  th, td { border-bottom: 1px solid #d0d7de; padding: 0.4rem 0.6rem; text-align: left; vertical-align: top; }
+  th { cursor: pointer; user-select: none; background: #f6f8fa; }
+  th[aria-sort="ascending"]::after { content: " \25B2"; }
+  th[aria-sort="descending"]::after { content: " \25BC"; }
+  td.line { text-align: right; }
+  .severity { font-weight: 600; }
+  .severity.error { color: #cf222e; }
+  .severity.warning { color: #9a6700; }
+  .severity.note { color: #0969da; }
+  pre { background: #f6f8fa; padding: 0.5rem; overflow-x: auto; margin: 0.4rem 0 0; }
+  pre .hit { background: #fff8c5; display: block; }
+  #entry-points { width: auto; margin-bottom: 1.5rem; }
+  #entry-points th { cursor: default; }
+  .entry-point.missing td:nth-child(2) { color: #cf222e; }
+</style>
+</head>
+<body>
+<h1>CosmWasm CodeQL Report</h1>
+HTML
+  if [ "$count" -eq 0 ]; then
+    echo "<p>No findings.</p>"
+  else
+    echo "<p id=\"summary\">$count finding(s) ($summary).</p>"
+  fi
+  entry_point_table
+  cat <<'HTML'
+<h2>Findings</h2>
+<div class="controls">
+  <select id="severity-filter" aria-label="Severity">
+    <option value="">All severities</option>
+    <option value="error">error</option>
+    <option value="warning">warning</option>
+    <option value="note">note</option>
+  </select>
+  <input id="text-filter" type="search" placeholder="Filter by query, file or message" aria-label="Filter">
+</div>
+<table id="findings">
+<thead>
+<tr><th data-type="number">Severity</th><th>Query</th><th>File</th><th data-type="number">Line</th><th>Message</th></tr>
+</thead>
+<tbody>
+HTML
+  if [ "$count" -gt 0 ]; then
+    while IFS=$'\t' read -r rank severity rule name file line message; do
+      local code
+      code=$(excerpt "$SOURCE_ROOT/$file" "$line")
+      printf '<tr class="finding" data-severity="%s"><td class="severity %s" data-sort="%s">%s</td><td><code title="%s">%s</code></td><td>%s</td><td class="line" data-sort="%s">%s</td><td>%s' \
+        "$severity" "$severity" "$rank" "$severity" "$(escape "$name")" "$(escape "$rule")" \
+        "$(escape "$file")" "$line" "$line" "$(escape "$message")"
+      if [ -n "$code" ]; then
+        printf '<details><summary>Code</summary><pre>%s</pre></details>' "$code"
+      fi
+      echo '</td></tr>'
+    done <<< "$results"
+  fi
+  cat <<'HTML'
+</tbody>
+</table>
+<script>
+(function () {
+  var table = document.getElementById("findings");
+  var body = table.tBodies[0];
+  var severity = document.getElementById("severity-filter");
+  var text = document.getElementById("text-filter");
+
+  // Cell text without the excerpt, which is the message cell's <details>
+  function cellText(cell) {
+    return cell.firstChild ? cell.firstChild.textContent : "";
+  }
+
+  function filter() {
+    var needle = text.value.toLowerCase();
+    Array.prototype.forEach.call(body.rows, function (row) {
+      var haystack = Array.prototype.map.call(row.cells, cellText).join(" ").toLowerCase();
+      var shown = (!severity.value || row.dataset.severity === severity.value) &&
+        haystack.indexOf(needle) !== -1;
+      row.hidden = !shown;
+    });
+  }
+  severity.addEventListener("change", filter);
+  text.addEventListener("input", filter);
+
+  Array.prototype.forEach.call(table.tHead.rows[0].cells, function (th, column) {
+    th.addEventListener("click", function () {
+      var ascending = th.getAttribute("aria-sort") !== "ascending";
+      var numeric = th.dataset.type === "number";
+      Array.prototype.forEach.call(table.tHead.rows[0].cells, function (other) {
+        other.removeAttribute("aria-sort");
+      });
+      th.setAttribute("aria-sort", ascending ? "ascending" : "descending");
+      var key = function (row) {
+        var cell = row.cells[column];
+        var value = cell.dataset.sort !== undefined ? cell.dataset.sort : cellText(cell);
+        return numeric ? Number(value) : value.toLowerCase();
+      };
+      Array.prototype.slice.call(body.rows)
+        .sort(function (a, b) {
+          var x = key(a), y = key(b);
+          return (x < y ? -1 : x > y ? 1 : 0) * (ascending ? 1 : -1);
+        })
+        .forEach(function (row) { body.appendChild(row); });
+    });
+  });
+})();
+</script>
+</body>
+</html>
+HTML
+}
+
+if [ -n "$OUT_FILE" ]; then
+  write_html > "$OUT_FILE"
+else
+  write_html
+fi
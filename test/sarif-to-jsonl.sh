@@ -0,0 +1,87 @@
+#!/bin/bash
+# Streams CodeQL SARIF results as JSON Lines, for log pipelines and scans
+# too large to hold in memory as one document.
+#
+# Usage:
+#   ./test/sarif-to-jsonl.sh <results.sarif>              # JSON Lines on stdout
+#   ./test/sarif-to-jsonl.sh <results.sarif> --out FILE   # Write to a file
+#
+# Prints one compact JSON object per unsuppressed result, in SARIF order:
+#
+#   {"query": "cosmwasm/...", "severity": "warning", "file": "src/contract.rs",
+#    "line": 42, "column": 5, "end_line": 42, "end_column": 5, "message": "...",
+#    "help_uri": "https://.../docs/query-help/....md", "cwe": ["CWE-862"]}
+#
+# Columns are 1-based and end_column is the last column of the reported span
+# (SARIF's endColumn minus one), so line 42, columns 5 to 5 is a single
+# character such as the `+` of an unchecked addition. A result without an
+# end column spans its start column only.
+# help_uri is the rule's helpUri and cwe its external/cwe tags; each is
+# omitted when the rule has none.
+#
+# The SARIF file is parsed as a stream (jq --stream): each result is printed
+# and flushed as soon as it has been read, and no more than one result is
+# held in memory at a time. Rule metadata, needed for default severities and
+# help links, is read first in a separate pass over the (small) tool section.
+#
+# Requirements: jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+
+SARIF_FILE=""
+OUT_FILE=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --out) OUT_FILE="$2"; shift 2 ;;
+    *) SARIF_FILE="$1"; shift ;;
+  esac
+done
+
+if [ ! -f "$SARIF_FILE" ]; then
+  echo "usage: sarif-to-jsonl.sh <results.sarif> [--out FILE]" >&2
+  exit 2
+fi
+
+# Rule id -> default level, help link and CWEs, from the registry and every
+# run's tool section (later entries win)
+rules=$(jq -cn --stream --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" '
+  [($registry[],
+    (fromstream(3 | truncate_stream(inputs | select(.[0][0] == "runs" and .[0][2] == "tool")))
+     | .driver.rules[]?, .extensions[]?.rules[]?))
+   | {key: .id, value: {
+       level: (.defaultConfiguration.level // "warning"),
+       help_uri: .helpUri,
+       cwe: [.properties.tags[]? | select(startswith("external/cwe/cwe-"))
+             | "CWE-" + ltrimstr("external/cwe/cwe-")]
+     }}]
+  | reduce .[] as $rule ({};
+      .[$rule.key] += ($rule.value | with_entries(select(.value != null and .value != []))))
+' "$SARIF_FILE")
+
+to_jsonl() {
+  jq -cn --stream --unbuffered --argjson rules "$rules" '
+    fromstream(4 | truncate_stream(inputs | select(.[0][0] == "runs" and .[0][2] == "results")))
+    | select((.suppressions // []) | length == 0)
+    | .locations[0].physicalLocation as $loc
+    | $rules[.ruleId] as $rule
+    | {
+        query: .ruleId,
+        severity: (.level // $rule.level // "warning"),
+        file: $loc.artifactLocation.uri,
+        line: $loc.region.startLine,
+        column: ($loc.region.startColumn // 1),
+        end_line: ($loc.region.endLine // $loc.region.startLine),
+        end_column: (if $loc.region.endColumn then $loc.region.endColumn - 1 else ($loc.region.startColumn // 1) end),
+        message: .message.text
+      }
+      + (if $rule.help_uri then {help_uri: $rule.help_uri} else {} end)
+      + (if $rule.cwe then {cwe: $rule.cwe} else {} end)
+  ' "$SARIF_FILE"
+}
+
+if [ -n "$OUT_FILE" ]; then
+  to_jsonl > "$OUT_FILE"
+else
+  to_jsonl
+fi
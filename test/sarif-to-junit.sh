@@ -0,0 +1,102 @@
+#!/bin/bash
+# Converts CodeQL SARIF output to JUnit XML for CI systems that gate on test
+# reports (GitLab, Jenkins, Buildkite).
+#
+# Usage:
+#   ./test/sarif-to-junit.sh <results.sarif>                      # JUnit XML on stdout
+#   ./test/sarif-to-junit.sh <results.sarif> --threshold error    # Only errors fail
+#   ./test/sarif-to-junit.sh <results.sarif> --out junit.xml      # Write to a file
+#
+# Each rule is a <testsuite> and each source file with results is a
+# <testcase> in every suite. Results at or above the threshold level
+# (error > warning > note; default warning) are <failure> elements; results
+# below it leave the testcase passing and are listed in <system-out>. Each
+# suite carries the rule's severity and confidence (@precision) as
+# <properties>.
+# Suppressed results are ignored.
+#
+# Requirements: jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+
+SARIF_FILE=""
+THRESHOLD="warning"
+OUT_FILE=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --threshold) THRESHOLD="$2"; shift 2 ;;
+    --out) OUT_FILE="$2"; shift 2 ;;
+    *) SARIF_FILE="$1"; shift ;;
+  esac
+done
+
+if [ ! -f "$SARIF_FILE" ] || [[ ! "$THRESHOLD" =~ ^(error|warning|note)$ ]]; then
+  echo "usage: sarif-to-junit.sh <results.sarif> [--threshold error|warning|note] [--out FILE]" >&2
+  exit 2
+fi
+
+write_junit() {
+  jq -r --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" --arg threshold "$THRESHOLD" '
+    def rank: {"error": 3, "warning": 2, "note": 1, "none": 0}[.] // 2;
+    def esc: tostring | gsub("&"; "&amp;") | gsub("<"; "&lt;") | gsub(">"; "&gt;") | gsub("\""; "&quot;");
+
+    [.runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?] as $rules
+    | ($registry + $rules | map({key: .id, value: (.defaultConfiguration.level // "warning")}) | from_entries) as $levels
+    | ($registry + $rules | map({key: .id, value: (.properties.precision // "unknown")}) | from_entries) as $precisions
+    | [.runs[].results[]
+        | select((.suppressions // []) | length == 0)
+        | .locations[0].physicalLocation as $loc
+        | {
+            rule: .ruleId,
+            file: $loc.artifactLocation.uri,
+            line: $loc.region.startLine,
+            column: ($loc.region.startColumn // 1),
+            level: (.level // $levels[.ruleId] // "warning"),
+            message: .message.text
+          }
+        | .failing = ((.level | rank) >= ($threshold | rank))
+      ] as $results
+    | ($results | map(.file) | unique) as $files
+    | (($rules | map(.id)) + ($results | map(.rule)) | unique) as $suites
+    | ($suites | map(. as $rule | {
+        rule: $rule,
+        cases: ((if ($files | length) > 0 then $files else [$rule] end) | map(. as $file | {
+          file: $file,
+          results: [$results[] | select(.rule == $rule and .file == $file)]
+        }))
+      })) as $report
+    | ($results | map(select(.failing)) | length) as $failures
+    | ($report | map(.cases | length) | add // 0) as $tests
+    | "<?xml version=\"1.0\" encoding=\"UTF-8\"?>",
+      "<testsuites name=\"cosmwasm-codeql\" tests=\"\($tests)\" failures=\"\($failures)\">",
+      ($report[]
+        | "  <testsuite name=\"\(.rule | esc)\" tests=\"\(.cases | length)\" failures=\"\([.cases[].results[] | select(.failing)] | length)\">",
+          "    <properties>",
+          "      <property name=\"severity\" value=\"\($levels[.rule] // "warning")\"/>",
+          "      <property name=\"confidence\" value=\"\($precisions[.rule] // "unknown")\"/>",
+          "    </properties>",
+          (.cases[] as $case
+            | ([$case.results[] | select(.failing)]) as $failed
+            | ([$case.results[] | select(.failing | not)]) as $passed
+            | if ($failed | length) == 0 and ($passed | length) == 0 then
+                "    <testcase name=\"\($case.file | esc)\" classname=\"\(.rule | esc)\"/>"
+              else
+                "    <testcase name=\"\($case.file | esc)\" classname=\"\(.rule | esc)\">",
+                ($failed[]
+                  | "      <failure type=\"\(.level)\" message=\"\(.message | esc)\">\(.file | esc):\(.line):\(.column): \(.message | esc)</failure>"),
+                (if ($passed | length) > 0 then
+                   "      <system-out>\($passed | map("\(.level): \(.file | esc):\(.line):\(.column): \(.message | esc)") | join("\n"))</system-out>"
+                 else empty end),
+                "    </testcase>"
+              end),
+          "  </testsuite>"),
+      "</testsuites>"
+  ' "$SARIF_FILE"
+}
+
+if [ -n "$OUT_FILE" ]; then
+  write_junit > "$OUT_FILE"
+else
+  write_junit
+fi
@@ -0,0 +1,120 @@
+#!/bin/bash
+# Converts CodeQL SARIF output to Language Server Protocol diagnostics, for
+# editor extensions that show findings as squiggles.
+#
+# Usage:
+#   ./test/sarif-to-lsp.sh <results.sarif>                          # JSON on stdout
+#   ./test/sarif-to-lsp.sh <results.sarif> --out FILE               # Write to a file
+#   ./test/sarif-to-lsp.sh <results.sarif> --source-root DIR --position-encoding utf-8
+#
+# Prints a JSON array with one PublishDiagnosticsParams object per file
+# ({"uri", "diagnostics"}), files and diagnostics sorted by position. Each
+# diagnostic has a 0-based range, a severity (error 1, warning 2, note 3,
+# none 4), `source: "cosmwasm-codeql"`, the query id as `code`, and the
+# message. URIs are the SARIF ones, relative to the analyzed source root.
+# Suppressed results are ignored.
+#
+# Positions are in UTF-16 code units, the LSP default and what CodeQL
+# reports. For clients that negotiate `positionEncoding: "utf-8"`, pass
+# --position-encoding utf-8 and the source root: characters are then byte
+# offsets, computed from the source lines. In ASCII files the two agree.
+#
+# Requirements: jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+
+SARIF_FILE=""
+OUT_FILE=""
+SOURCE_ROOT=""
+ENCODING="utf-16"
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --out) OUT_FILE="$2"; shift 2 ;;
+    --source-root) SOURCE_ROOT="$2"; shift 2 ;;
+    --position-encoding) ENCODING="$2"; shift 2 ;;
+    *) SARIF_FILE="$1"; shift ;;
+  esac
+done
+
+if [ ! -f "$SARIF_FILE" ] || [[ ! "$ENCODING" =~ ^utf-(8|16)$ ]] ||
+  { [ "$ENCODING" = "utf-8" ] && [ ! -d "$SOURCE_ROOT" ]; }; then
+  echo "usage: sarif-to-lsp.sh <results.sarif> [--out FILE] [--source-root DIR --position-encoding utf-8|utf-16]" >&2
+  exit 2
+fi
+
+# Prints {"<uri>": [lines]} for each reported file found under the source
+# root; only needed to compute byte offsets.
+source_lines() {
+  local uri
+  {
+    echo '{}'
+    jq -r '[.runs[].results[].locations[0].physicalLocation.artifactLocation.uri] | unique[]' "$SARIF_FILE" |
+      while IFS= read -r uri; do
+        [ -f "$SOURCE_ROOT/$uri" ] || continue
+        jq -Rs --arg uri "$uri" '{($uri): split("\n")}' "$SOURCE_ROOT/$uri"
+      done
+  } | jq -s 'add'
+}
+
+SOURCES='{}'
+[ "$ENCODING" = "utf-8" ] && SOURCES=$(source_lines)
+
+to_lsp() {
+  jq --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" --arg encoding "$ENCODING" --argjson sources "$SOURCES" '
+    def severity: {"error": 1, "warning": 2, "note": 3, "none": 4}[.] // 2;
+    # UTF-8 length of the first $units UTF-16 code units of $text; units past
+    # the end of the line count as one byte each
+    def utf8_offset($text; $units):
+      reduce ($text | explode[]) as $cp ({units: 0, bytes: 0};
+        if .units >= $units then .
+        else {
+          units: (.units + (if $cp > 65535 then 2 else 1 end)),
+          bytes: (.bytes + (if $cp < 128 then 1 elif $cp < 2048 then 2 elif $cp < 65536 then 3 else 4 end))
+        } end)
+      | .bytes + ([$units - .units, 0] | max);
+    # 0-based position for 1-based SARIF line and column in file $uri
+    def position($uri; $line; $column):
+      {
+        line: ($line - 1),
+        character:
+          (if $encoding == "utf-8" and $sources[$uri][$line - 1] != null
+           then utf8_offset($sources[$uri][$line - 1]; $column - 1)
+           else $column - 1 end)
+      };
+    ([$registry[], .runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?]
+     | map({key: .id, value: .}) | from_entries) as $rules
+    | [.runs[].results[]
+        | select((.suppressions // []) | length == 0)
+        | .locations[0].physicalLocation as $loc
+        | $loc.artifactLocation.uri as $uri
+        | ($loc.region.startLine) as $start_line
+        | ($loc.region.startColumn // 1) as $start_column
+        | {
+            uri: $uri,
+            diagnostic: {
+              range: {
+                start: position($uri; $start_line; $start_column),
+                end: position($uri; $loc.region.endLine // $start_line;
+                  $loc.region.endColumn // $start_column)
+              },
+              severity: (.level // $rules[.ruleId].defaultConfiguration.level // "warning" | severity),
+              code: .ruleId,
+              source: "cosmwasm-codeql",
+              message: .message.text
+            }
+          }]
+    | group_by(.uri)
+    | map({
+        uri: .[0].uri,
+        diagnostics: (map(.diagnostic)
+          | sort_by(.range.start.line, .range.start.character, .code))
+      })
+  ' "$SARIF_FILE"
+}
+
+if [ -n "$OUT_FILE" ]; then
+  to_lsp > "$OUT_FILE"
+else
+  to_lsp
+fi
@@ -0,0 +1,197 @@
+#!/bin/bash
+# Renders CodeQL SARIF output as a Markdown report for PR comments and audit
+# documents.
+#
+# Usage:
+#   ./test/sarif-to-markdown.sh <source-root> <results.sarif>              # Markdown on stdout
+#   ./test/sarif-to-markdown.sh <source-root> <results.sarif> --out FILE   # Write to a file
+#
+# Findings are grouped by severity (error, warning, note), then by rule id.
+# Each rule lists its remediation advice (the Recommendation section of
+# docs/query-help/<rule>.md) and a "Learn more" line linking the help page
+# and its CWE entries from the rule registry. Each finding shows its
+# file:line, message and a three-line excerpt read from <source-root> with
+# the reported line marked `>`. Suppressed results are ignored. The header has a table of the
+# contract's entry points from test/entry-points.sh: which standard handlers
+# are present and which are missing.
+#
+# Requirements: jq
+set -eo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+PROJECT_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+REGISTRY="$PROJECT_ROOT/src/registry/rules.json"
+
+SOURCE_ROOT=""
+SARIF_FILE=""
+OUT_FILE=""
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --out) OUT_FILE="$2"; shift 2 ;;
+    *)
+      if [ -z "$SOURCE_ROOT" ]; then SOURCE_ROOT="$1"; else SARIF_FILE="$1"; fi
+      shift
+      ;;
+  esac
+done
+
+if [ -z "$SOURCE_ROOT" ] || [ ! -f "$SARIF_FILE" ]; then
+  echo "usage: sarif-to-markdown.sh <source-root> <results.sarif> [--out FILE]" >&2
+  exit 2
+fi
+
+# Prints one tab-separated line per unsuppressed result (severity, rule id,
+# rule name, file, line, message), ordered by severity, rule, file and line.
+list_results() {
+  jq -r --argjson registry "$(bash "$SCRIPT_DIR/rules.sh" --sarif-rules)" '
+    def rank: {"error": 3, "warning": 2, "note": 1, "none": 0}[.] // 2;
+    ([$registry[], .runs[].tool.driver.rules[]?, .runs[].tool.extensions[]?.rules[]?]
+     | map({key: .id, value: .}) | from_entries) as $rules
+    | [.runs[].results[]
+        | select((.suppressions // []) | length == 0)
+        | .locations[0].physicalLocation as $loc
+        | {
+            severity: (.level // $rules[.ruleId].defaultConfiguration.level // "warning"),
+            rule: .ruleId,
+            name: ($rules[.ruleId].shortDescription.text // .ruleId),
+            file: $loc.artifactLocation.uri,
+            line: $loc.region.startLine,
+            message: .message.text
+          }]
+    | sort_by(-(.severity | rank), .rule, .file, .line)[]
+    | [.severity, .rule, .name, .file, .line, .message]
+    | @tsv
+  ' "$SARIF_FILE"
+}
+
+# Prints the Recommendation section of the rule's query help, if any.
+remediation() {
+  local help
+  help=$(jq -r --arg id "$1" '.rules[] | select(.id == $id) | .help' "$REGISTRY")
+  [ -n "$help" ] && [ -f "$PROJECT_ROOT/$help" ] || return 0
+  help="$PROJECT_ROOT/$help"
+  awk '
+    /^## / { in_section = ($0 == "## Recommendation"); next }
+    in_section { lines[++n] = $0 }
+    END {
+      first = 1; last = n
+      while (first <= last && lines[first] == "") first++
+      while (last >= first && lines[last] == "") last--
+      for (i = first; i <= last; i++) print lines[i]
+    }
+  ' "$help"
+}
+
+# Prints links to the rule's help page and CWE entries, if it is registered.
+learn_more() {
+  jq -r --arg id "$1" '
+    .helpBaseUri as $base
+    | .rules[] | select(.id == $id)
+    | ["[Query help](\($base + .help))"]
+      + [.cwe[] | "[\(.)](https://cwe.mitre.org/data/definitions/\(ltrimstr("CWE-")).html)"]
+    | join(" · ")
+  ' "$REGISTRY"
+}
+
+# Prints the excerpt lines of the file (test/excerpt-range.sh), with the
+# reported line marked.
+excerpt() {
+  local file="$1" line="$2" range first last
+  range=$(bash "$SCRIPT_DIR/excerpt-range.sh" "$file" "$line")
+  [ -n "$range" ] || return 0
+  IFS=$'\t' read -r first last <<< "$range"
+  echo '```rust'
+  awk -v first="$first" -v last="$last" -v hit="$line" '
+    NR >= first && NR <= last { printf "%s %4d | %s\n", (NR == hit ? ">" : " "), NR, $0 }
+    NR > last { exit }
+  ' "$file"
+  echo '```'
+}
+
+# Prints the entry point table, if the source root defines any.
+entry_point_table() {
+  local handlers
+  handlers=$(bash "$SCRIPT_DIR/entry-points.sh" "$SOURCE_ROOT")
+  [ -n "$handlers" ] || return 0
+  echo ""
+  echo "## Entry Points"
+  echo ""
+  echo "| Handler | Status | Location |"
+  echo "|---------|--------|----------|"
+  while IFS=$'\t' read -r handler status location; do
+    [ -n "$location" ] && location="\`$location\`" || location="—"
+    echo "| \`$handler\` | $status | $location |"
+  done <<< "$handlers"
+}
+
+heading() {
+  case "$1" in
+    error) echo "Errors" ;;
+    warning) echo "Warnings" ;;
+    note) echo "Notes" ;;
+    *) echo "$1" ;;
+  esac
+}
+
+write_markdown() {
+  local results count
+  results=$(list_results)
+  count=$(printf '%s\n' "$results" | grep -c . || true)
+
+  echo "# CosmWasm CodeQL Report"
+  echo ""
+  if [ "$count" -eq 0 ]; then
+    echo "No findings."
+    entry_point_table
+    return
+  fi
+  local summary=""
+  for level in error warning note; do
+    local n
+    n=$(printf '%s\n' "$results" | awk -F'\t' -v l="$level" '$1 == l' | grep -c . || true)
+    [ "$n" -gt 0 ] && summary+="${summary:+, }$level: $n"
+  done
+  echo "$count finding(s) ($summary)."
+  entry_point_table
+
+  local current_severity="" current_rule=""
+  while IFS=$'\t' read -r severity rule name file line message; do
+    if [ "$severity" != "$current_severity" ]; then
+      echo ""
+      echo "## $(heading "$severity")"
+      current_severity="$severity"
+      current_rule=""
+    fi
+    if [ "$rule" != "$current_rule" ]; then
+      echo ""
+      echo "### \`$rule\` — $name"
+      local advice
+      advice=$(remediation "$rule")
+      if [ -n "$advice" ]; then
+        echo ""
+        echo "**Remediation:** $advice"
+      fi
+      local links
+      links=$(learn_more "$rule")
+      if [ -n "$links" ]; then
+        echo ""
+        echo "**Learn more:** $links"
+      fi
+      current_rule="$rule"
+    fi
+    echo ""
+    echo "**\`$file:$line\`** — $message"
+    local code
+    code=$(excerpt "$SOURCE_ROOT/$file" "$line")
+    if [ -n "$code" ]; then
+      echo ""
+      echo "$code"
+    fi
+  done <<< "$results"
+}
+
+if [ -n "$OUT_FILE" ]; then
+  write_markdown > "$OUT_FILE"
+else
+  write_markdown
+fi
@@ -0,0 +1,37 @@
+#!/bin/bash
+# Reports `// cosmwasm-codeql: ignore <id>` comments that suppressed no result,
+# so stale suppressions get cleaned up.
+# Usage: unused-suppressions.sh <source-root> <results.sarif>
+# The SARIF file must come from `codeql database analyze` including
+# src/queries/AlertSuppression.ql. Prints one warning line per unused id.
+# Requirements: jq
+set -eo pipefail
+
+SOURCE_ROOT="${1:?usage: unused-suppressions.sh <source-root> <results.sarif>}"
+SARIF_FILE="${2:?usage: unused-suppressions.sh <source-root> <results.sarif>}"
+
+# "uri:line:ruleId" for every result marked as suppressed in source
+suppressed=$(jq -r '
+  .runs[].results[]
+  | select((.suppressions // []) | length > 0)
+  | .locations[0].physicalLocation
+    as $loc
+  | "\($loc.artifactLocation.uri):\($loc.region.startLine):\(.ruleId)"
+' "$SARIF_FILE")
+
+cd "$SOURCE_ROOT"
+grep -rnE --include='*.rs' '//+[[:space:]]*cosmwasm-codeql:[[:space:]]*ignore[[:space:]]' . |
+  while IFS=: read -r file line text; do
+    file="${file#./}"
+    ids=$(echo "$text" | sed -E 's/.*cosmwasm-codeql:[[:space:]]*ignore[[:space:]]+//; s/[[:space:]]+reason=.*//')
+    IFS=',' read -ra id_list <<< "$ids"
+    for id in "${id_list[@]}"; do
+      id=$(echo "$id" | xargs)
+      [ -z "$id" ] && continue
+      [[ "$id" == cosmwasm/* ]] || id="cosmwasm/$id"
+      # The suppression applies to the line after the comment
+      if ! grep -qxF "$file:$((line + 1)):$id" <<< "$suppressed"; then
+        echo "warning: unused suppression of $id at $file:$line"
+      fi
+    done
+  done